@@ -18,6 +18,7 @@ fn main() {
 #[cfg(all(target_arch = "arm", target_os = "none"))]
 use {
     cortex_m_rt::entry,
+    debounced_pin::hal02::Compat,
     debounced_pin::prelude::*,
     debounced_pin::ActiveHigh,
     panic_semihosting as _,
@@ -55,7 +56,7 @@ fn main() -> ! {
         .into_floating_input(&mut gpioa.moder, &mut gpioa.pupdr);
 
     // button is externally pulled down, and is pulled up via a button press
-    let mut user_button = DebouncedInputPin::new(user_button, ActiveHigh);
+    let mut user_button = DebouncedInputPin::new(Compat(user_button), ActiveHigh);
 
     loop {
         user_button.update().unwrap();
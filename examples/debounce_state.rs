@@ -1,4 +1,4 @@
-//! A debounced pin example using the debounce state.
+//! A debounced pin example using the debounced edges.
 //! Target board: STM32F3DISCOVERY
 
 #![deny(unsafe_code)]
@@ -18,6 +18,7 @@ fn main() {
 #[cfg(all(target_arch = "arm", target_os = "none"))]
 use {
     cortex_m_rt::entry,
+    debounced_pin::hal02::Compat,
     debounced_pin::prelude::*,
     debounced_pin::ActiveHigh,
     panic_semihosting as _,
@@ -50,34 +51,21 @@ fn main() -> ! {
         .into_floating_input(&mut gpioa.moder, &mut gpioa.pupdr);
 
     // button is externally pulled down, and is pulled up via a button press
-    let mut user_button = DebouncedInputPin::new(user_button, ActiveHigh);
+    let mut user_button = DebouncedInputPin::new(Compat(user_button), ActiveHigh);
 
     let mut led_state = false;
 
     loop {
         delay.delay_ms(1_u16);
-        match user_button.update().unwrap() {
-            // Pin is not active
-            DebounceState::NotActive => continue,
-
-            // Pin was reset
-            DebounceState::Reset => {}
-            // Pin is active but still debouncing
-            DebounceState::Debouncing => continue,
-
-            // Pin is active and debounced.
-            DebounceState::Active => {
-                if led_state {
-                    led.set_low().unwrap();
-                } else {
-                    led.set_high().unwrap();
-                }
-                // As in this example it is constantly looping,
-                // delay the next update loop for a bit
-                delay.delay_ms(200_u16);
-
-                led_state = !led_state;
+        // Only toggle the LED once per debounced press.
+        if let Some(Edge::Pressed) = user_button.update_events().unwrap() {
+            if led_state {
+                led.set_low().unwrap();
+            } else {
+                led.set_high().unwrap();
             }
+
+            led_state = !led_state;
         }
     }
 }
@@ -0,0 +1,85 @@
+//! Debouncing a group of pins together.
+
+use crate::{Debounce, DebounceState, Edge};
+
+/// An error from one pin of a `DebouncedPinArray`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PinError<E> {
+    /// The index of the pin which failed.
+    pub index: usize,
+    /// The error of the pin.
+    pub error: E,
+}
+
+/// A group of `N` debounced pins updated together.
+///
+/// Holds anything implementing `Debounce` with `DebounceState`, such as
+/// `DebouncedInputPin`s. Pins of different types can be grouped by type-erasing
+/// them, as `&mut dyn Debounce<Error = E, State = DebounceState>`.
+pub struct DebouncedPinArray<P, const N: usize> {
+    /// The debounced pins.
+    pub pins: [P; N],
+
+    /// The edge every pin caused during the last update.
+    edges: [Option<Edge>; N],
+}
+
+impl<P: Debounce<State = DebounceState>, const N: usize> DebouncedPinArray<P, N> {
+    /// Initializes a new group of debounced pins.
+    pub fn new(pins: [P; N]) -> Self {
+        Self {
+            pins,
+            edges: [None; N],
+        }
+    }
+
+    /// Returns the debounce state of every pin from the last update.
+    pub fn states(&self) -> [DebounceState; N] {
+        let mut states = [DebounceState::NotActive; N];
+        for (state, pin) in states.iter_mut().zip(self.pins.iter()) {
+            *state = pin.state();
+        }
+        states
+    }
+
+    /// Checks if the pin at `index` is debounced active.
+    pub fn is_active(&self, index: usize) -> bool {
+        self.pins[index].state().is_active()
+    }
+
+    /// Checks if every pin is settled, so `update_all()` can stop being called
+    /// until a pin changes.
+    pub fn is_settled(&self) -> bool {
+        self.pins.iter().all(|pin| pin.is_settled())
+    }
+
+    /// Returns the edge every pin caused during the last `update_all()`, if any.
+    ///
+    /// Also holds the edges of the pins which did not fail when `update_all()`
+    /// returned an error.
+    pub fn edges(&self) -> [Option<Edge>; N] {
+        self.edges
+    }
+
+    /// Updates every pin and returns the edge each of them caused, if any.
+    ///
+    /// Every pin is updated even if one fails, and the first failure is
+    /// returned. The edges of the other pins are then available from `edges()`.
+    pub fn update_all(&mut self) -> Result<[Option<Edge>; N], PinError<P::Error>> {
+        let mut failure = None;
+        for (index, (edge, pin)) in self.edges.iter_mut().zip(self.pins.iter_mut()).enumerate() {
+            match pin.update_events() {
+                Ok(pin_edge) => *edge = pin_edge,
+                Err(error) => {
+                    *edge = None;
+                    failure.get_or_insert(PinError { index, error });
+                }
+            }
+        }
+
+        match failure {
+            Some(failure) => Err(failure),
+            None => Ok(self.edges),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Runtime configuration for `DebouncedInputPin`.
+
+use crate::{DebouncedInputPin, RuntimeThreshold};
+use embedded_hal::digital::InputPin;
+
+/// A builder for a `DebouncedInputPin` configured at runtime.
+///
+/// Created with `DebouncedInputPin::builder()`.
+pub struct DebouncedInputPinBuilder<T: InputPin, A> {
+    /// The pin to be debounced.
+    pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The number of active ticks required before the pin is debounced.
+    threshold: u16,
+
+    /// The number of inactive ticks required before the pin is released.
+    release_threshold: u16,
+
+    /// The number of ticks the pin is ignored for after a reset.
+    blanking: u16,
+}
+
+impl<T: InputPin, A> DebouncedInputPinBuilder<T, A> {
+    pub(crate) fn new(pin: T, activeness: A) -> Self {
+        Self {
+            pin,
+            activeness,
+            threshold: 10,
+            release_threshold: 0,
+            blanking: 0,
+        }
+    }
+
+    /// Sets the number of active ticks required before the pin is debounced.
+    pub fn threshold(mut self, threshold: u16) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the number of inactive ticks required before the pin is released.
+    pub fn release_threshold(mut self, release_threshold: u16) -> Self {
+        self.release_threshold = release_threshold;
+        self
+    }
+
+    /// Sets the number of ticks the pin is ignored for after construction and
+    /// after a reset.
+    pub fn blanking(mut self, blanking: u16) -> Self {
+        self.blanking = blanking;
+        self
+    }
+
+    /// Builds the debounced input pin.
+    pub fn build(self) -> DebouncedInputPin<T, A, RuntimeThreshold> {
+        let threshold = RuntimeThreshold(self.threshold);
+        let mut pin = DebouncedInputPin::with_threshold(self.pin, self.activeness, threshold);
+        pin.release_threshold = self.release_threshold;
+        pin.blanking = self.blanking;
+        pin.reset();
+        pin
+    }
+}
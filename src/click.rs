@@ -0,0 +1,120 @@
+//! Multi-click recognition on top of a debounced pin.
+
+use crate::{Debounce, DebounceState, Edge};
+
+/// A number of clicks reported by `ClickRecognizer`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Click(pub u8);
+
+/// Counts clicks of a debounced pin, such as double and triple clicks.
+///
+/// Wraps anything implementing `Debounce` with `DebounceState`, such as a
+/// `DebouncedInputPin`. A click is a debounced press followed by a release.
+/// Clicks which start within `gap` ticks of the previous release are counted
+/// together, and `Click(n)` is reported once the gap expires.
+///
+/// Once `max_clicks` clicks are counted they are reported right away, so a
+/// maximum of 1 disables multi-click and reports every click on its release.
+pub struct ClickRecognizer<D> {
+    /// The wrapped debounced pin.
+    pub pin: D,
+
+    /// The number of ticks after a release in which the next click is counted.
+    gap: u16,
+
+    /// The number of clicks which are reported without waiting for the gap.
+    max_clicks: u8,
+
+    /// The number of clicks counted so far.
+    clicks: u8,
+
+    /// The number of ticks since the last release.
+    idle: u16,
+
+    /// The click from the last update.
+    click: Option<Click>,
+}
+
+impl<D: Debounce<State = DebounceState>> ClickRecognizer<D> {
+    /// Initializes a new click recognizer.
+    pub fn new(pin: D, gap: u16) -> Self {
+        Self {
+            pin,
+            gap,
+            max_clicks: u8::MAX,
+            clicks: 0,
+            idle: 0,
+            click: None,
+        }
+    }
+
+    /// Returns the number of ticks after a release in which the next click is counted.
+    pub fn gap(&self) -> u16 {
+        self.gap
+    }
+
+    /// Changes the number of ticks after a release in which the next click is counted.
+    pub fn set_gap(&mut self, gap: u16) {
+        self.gap = gap;
+    }
+
+    /// Returns the number of clicks which are reported without waiting for the gap.
+    pub fn max_clicks(&self) -> u8 {
+        self.max_clicks
+    }
+
+    /// Changes the number of clicks which are reported without waiting for the gap.
+    pub fn set_max_clicks(&mut self, max_clicks: u8) {
+        self.max_clicks = max_clicks.max(1);
+    }
+
+    /// Reports the clicks counted so far and starts over.
+    fn report(&mut self) -> Option<Click> {
+        let clicks = self.clicks;
+        self.clicks = 0;
+        Some(Click(clicks))
+    }
+}
+
+impl<D: Debounce<State = DebounceState>> Debounce for ClickRecognizer<D> {
+    type Error = D::Error;
+    type State = Option<Click>;
+
+    /// Returns the click from the last update.
+    fn state(&self) -> Self::State {
+        self.click
+    }
+
+    /// Updates the wrapped pin and the click count.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.click = match self.pin.update_events()? {
+            Some(Edge::Released) => {
+                self.clicks += 1;
+                self.idle = 0;
+                if self.clicks >= self.max_clicks {
+                    self.report()
+                } else {
+                    None
+                }
+            }
+            None if self.clicks > 0 && !self.pin.state().is_active() => {
+                self.idle += 1;
+                if self.idle >= self.gap {
+                    self.report()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        Ok(self.click)
+    }
+
+    /// Checks if the wrapped pin is settled and no clicks are waiting for the gap.
+    fn is_settled(&self) -> bool {
+        self.pin.is_settled() && self.clicks == 0
+    }
+}
@@ -0,0 +1,213 @@
+//! A quadrature rotary encoder decoder.
+
+use crate::{Debounce, DebounceState, Edge};
+use embedded_hal::digital::InputPin;
+
+/// The direction of every transition between two `(A, B)` states, indexed by
+/// `previous << 2 | current`. Invalid transitions, where both channels change at
+/// once, are ignored.
+const TRANSITIONS: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
+
+/// The number of quadrature transitions between two detents of an encoder.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum StepsPerDetent {
+    /// Every transition is a detent.
+    One = 1,
+    /// Every second transition is a detent.
+    Two = 2,
+    /// Every full quadrature cycle is a detent.
+    Four = 4,
+}
+
+/// The result of updating a `QuadratureEncoder` with a push button.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct EncoderState {
+    /// The detents turned during this update, positive for clockwise.
+    pub detents: i8,
+    /// The edge of the push button during this update, if any.
+    pub button: Option<Edge>,
+}
+
+/// A decoder for a quadrature rotary encoder on two `InputPin`s.
+///
+/// Contact bounce is filtered by a Gray-code state machine instead of a debounce
+/// delay. Every valid transition moves the position one step, so a channel
+/// bouncing back and forth cancels itself out, and transitions where both
+/// channels change at once are rejected. Clockwise rotation, where A leads B,
+/// counts up.
+///
+/// The channels are taken to rest on a detent at the first update, so encoders
+/// which rest with both channels high work as well as those which rest low.
+///
+/// An optional push button, such as a `DebouncedInputPin`, is updated together
+/// with the encoder when added with `with_button()`.
+///
+/// Requires `update()` to be called every ~1ms, or faster for fast rotation.
+pub struct QuadratureEncoder<A: InputPin, B: InputPin, S = ()> {
+    /// The A channel pin.
+    pub a: A,
+
+    /// The B channel pin.
+    pub b: B,
+
+    /// The push button.
+    pub button: S,
+
+    /// The number of transitions between two detents.
+    steps_per_detent: StepsPerDetent,
+
+    /// The last `(A, B)` state, unknown before the first update.
+    channels: Option<u8>,
+
+    /// The `(A, B)` state at a detent, taken from the first update.
+    rest: u8,
+
+    /// The transitions since the last detent, cleared whenever the channels
+    /// rest on a detent.
+    steps: i8,
+
+    /// The detents turned during the last update.
+    detents: i8,
+
+    /// The edge of the push button during the last update.
+    button_edge: Option<Edge>,
+}
+
+impl<A: InputPin, B: InputPin<Error = A::Error>> QuadratureEncoder<A, B> {
+    /// Initializes a new encoder decoder without a push button.
+    pub fn new(a: A, b: B, steps_per_detent: StepsPerDetent) -> Self {
+        Self {
+            a,
+            b,
+            button: (),
+            steps_per_detent,
+            channels: None,
+            rest: 0,
+            steps: 0,
+            detents: 0,
+            button_edge: None,
+        }
+    }
+
+    /// Adds a debounced push button which is updated together with the encoder.
+    pub fn with_button<S>(self, button: S) -> QuadratureEncoder<A, B, S>
+    where
+        S: Debounce<State = DebounceState, Error = A::Error>,
+    {
+        QuadratureEncoder {
+            a: self.a,
+            b: self.b,
+            button,
+            steps_per_detent: self.steps_per_detent,
+            channels: self.channels,
+            rest: self.rest,
+            steps: self.steps,
+            detents: self.detents,
+            button_edge: None,
+        }
+    }
+}
+
+impl<A: InputPin, B: InputPin<Error = A::Error>, S> QuadratureEncoder<A, B, S> {
+    /// Returns the detents turned during the last update, positive for clockwise.
+    pub fn detents(&self) -> i8 {
+        self.detents
+    }
+
+    /// Checks if the encoder rests on a detent with the given `(A, B)` state.
+    fn is_detent(&self, channels: u8) -> bool {
+        match self.steps_per_detent {
+            StepsPerDetent::One => true,
+            StepsPerDetent::Two => channels == self.rest || channels == self.rest ^ 0b11,
+            StepsPerDetent::Four => channels == self.rest,
+        }
+    }
+
+    /// Checks if the encoder rests on a detent, or has not been read yet.
+    fn rests_on_detent(&self) -> bool {
+        self.channels
+            .is_none_or(|channels| self.is_detent(channels))
+    }
+
+    /// Reads both channels and returns the detents turned since the last update.
+    fn decode(&mut self) -> Result<i8, A::Error> {
+        let current = (self.a.is_high()? as u8) << 1 | self.b.is_high()? as u8;
+        let previous = match self.channels.replace(current) {
+            Some(previous) => previous,
+            None => {
+                self.rest = current;
+                current
+            }
+        };
+        self.steps += TRANSITIONS[(previous << 2 | current) as usize];
+
+        let steps_per_detent = self.steps_per_detent as i8;
+        self.detents = if self.steps >= steps_per_detent {
+            self.steps -= steps_per_detent;
+            1
+        } else if self.steps <= -steps_per_detent {
+            self.steps += steps_per_detent;
+            -1
+        } else {
+            0
+        };
+
+        // Realign on every detent, so steps lost to a skipped transition do
+        // not shift later detents.
+        if self.is_detent(current) {
+            self.steps = 0;
+        }
+
+        Ok(self.detents)
+    }
+}
+
+impl<A: InputPin, B: InputPin<Error = A::Error>> Debounce for QuadratureEncoder<A, B> {
+    type Error = A::Error;
+    type State = i8;
+
+    /// Returns the detents turned during the last update.
+    fn state(&self) -> Self::State {
+        self.detents
+    }
+
+    /// Reads both channels and returns the detents turned since the last update.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.decode()
+    }
+
+    /// Checks if the encoder rests on a detent.
+    fn is_settled(&self) -> bool {
+        self.rests_on_detent()
+    }
+}
+
+impl<A, B, S> Debounce for QuadratureEncoder<A, B, S>
+where
+    A: InputPin,
+    B: InputPin<Error = A::Error>,
+    S: Debounce<State = DebounceState, Error = A::Error>,
+{
+    type Error = A::Error;
+    type State = EncoderState;
+
+    /// Returns the detents turned and the button edge during the last update.
+    fn state(&self) -> Self::State {
+        EncoderState {
+            detents: self.detents,
+            button: self.button_edge,
+        }
+    }
+
+    /// Reads both channels and updates the push button.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.decode()?;
+        self.button_edge = self.button.update_events()?;
+        Ok(self.state())
+    }
+
+    /// Checks if the encoder rests on a detent and the push button is settled.
+    fn is_settled(&self) -> bool {
+        self.rests_on_detent() && self.button.is_settled()
+    }
+}
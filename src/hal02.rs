@@ -0,0 +1,91 @@
+//! Support for `embedded-hal` 0.2 pins and delays.
+//!
+//! Available with the `embedded-hal-02` feature. The debouncers in this crate
+//! also implement the 0.2 `digital::v2::InputPin` trait with this feature.
+//!
+//! Input pins are adapted by `Compat`, output pins by `CompatOutput` and delays
+//! by `CompatDelay`, so a `KeyMatrix` can be scanned with 0.2 parts as well.
+
+use core::fmt::Debug;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{Error, ErrorKind, ErrorType, InputPin, OutputPin};
+use embedded_hal_02::blocking::delay::DelayUs;
+use embedded_hal_02::digital::v2;
+
+/// An `embedded-hal` 0.2 input pin adapted to the 1.0 `InputPin` trait, so it
+/// can be debounced.
+///
+/// ```rust,ignore
+/// let pin = DebouncedInputPin::new(Compat(hal_02_pin), ActiveHigh);
+/// ```
+pub struct Compat<P>(pub P);
+
+/// An `embedded-hal` 0.2 output pin adapted to the 1.0 `OutputPin` trait, such
+/// as a row pin of a `KeyMatrix`.
+pub struct CompatOutput<P>(pub P);
+
+/// An `embedded-hal` 0.2 microsecond delay adapted to the 1.0 `DelayNs` trait,
+/// such as the delay passed to `KeyMatrix::scan()`.
+///
+/// Delays in nanoseconds are rounded up to whole microseconds.
+pub struct CompatDelay<D>(pub D);
+
+/// An error of an `embedded-hal` 0.2 pin adapted by `Compat`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CompatError<E>(pub E);
+
+impl<E: Debug> Error for CompatError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<P: v2::InputPin> ErrorType for Compat<P>
+where
+    P::Error: Debug,
+{
+    type Error = CompatError<P::Error>;
+}
+
+impl<P: v2::InputPin> InputPin for Compat<P>
+where
+    P::Error: Debug,
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.0.is_high().map_err(CompatError)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.0.is_low().map_err(CompatError)
+    }
+}
+
+impl<P: v2::OutputPin> ErrorType for CompatOutput<P>
+where
+    P::Error: Debug,
+{
+    type Error = CompatError<P::Error>;
+}
+
+impl<P: v2::OutputPin> OutputPin for CompatOutput<P>
+where
+    P::Error: Debug,
+{
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.set_low().map_err(CompatError)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.set_high().map_err(CompatError)
+    }
+}
+
+impl<D: DelayUs<u32>> DelayNs for CompatDelay<D> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_us(ns.div_ceil(1_000));
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.0.delay_us(us);
+    }
+}
@@ -0,0 +1,120 @@
+//! An integrating (up/down counter) debouncer.
+
+use crate::{Activeness, ConstThreshold, Debounce, DebounceState, Threshold};
+use embedded_hal::digital::InputPin;
+
+/// An input pin debounced by an integrator.
+///
+/// Every active sample counts the integrator up and every inactive sample counts
+/// it down, saturating at 0 and the threshold `N`. The pin becomes active once
+/// the integrator reaches `N` and is released once it drops back to 0, so a
+/// noisy sample only delays the transition instead of restarting it. The
+/// threshold is given by `Th`, which defaults to `ConstThreshold<10>`. A
+/// threshold of 0 behaves like 1.
+///
+/// Requires `update()` to be called every ~1ms.
+pub struct IntegratingDebouncer<T: InputPin, A, Th = ConstThreshold<10>> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The value the integrator saturates at.
+    threshold: Th,
+
+    /// The debounced pin state.
+    debounce_state: DebounceState,
+
+    /// The integrator.
+    integrator: u16,
+}
+
+impl<T: InputPin, A> IntegratingDebouncer<T, A> {
+    /// Initializes a new integrating debouncer.
+    pub fn new(pin: T, activeness: A) -> Self {
+        Self::new_with_threshold(pin, activeness)
+    }
+}
+
+impl<T: InputPin, A, const N: u16> IntegratingDebouncer<T, A, ConstThreshold<N>> {
+    /// Initializes a new integrating debouncer which saturates at `N`.
+    pub fn new_with_threshold(pin: T, activeness: A) -> Self {
+        Self {
+            pin,
+            activeness,
+            threshold: ConstThreshold,
+            debounce_state: DebounceState::NotActive,
+            integrator: 0,
+        }
+    }
+}
+
+impl<T: InputPin, A, Th: Threshold> IntegratingDebouncer<T, A, Th> {
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Returns the value the integrator saturates at, treating 0 like 1.
+    fn saturation(&self) -> u16 {
+        self.threshold.ticks().max(1)
+    }
+
+    /// Drives the integrator with a sample of whether the pin is active.
+    fn integrate(&mut self, active: bool) -> DebounceState {
+        let threshold = self.saturation();
+        if active {
+            if self.integrator < threshold {
+                self.integrator += 1;
+            }
+        } else {
+            self.integrator = self.integrator.saturating_sub(1);
+        }
+
+        let was_active = self.debounce_state.is_active();
+        self.debounce_state = if self.integrator >= threshold {
+            DebounceState::Active
+        } else if self.integrator == 0 {
+            if was_active {
+                DebounceState::Reset
+            } else {
+                DebounceState::NotActive
+            }
+        } else if was_active {
+            DebounceState::Releasing
+        } else {
+            DebounceState::Debouncing
+        };
+
+        self.debounce_state
+    }
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> Debounce for IntegratingDebouncer<T, A, Th> {
+    type Error = T::Error;
+    type State = DebounceState;
+
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
+    }
+
+    /// Updates the integrator.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        let active = self.activeness.is_active(&mut self.pin)?;
+        Ok(self.integrate(active))
+    }
+
+    /// Checks if the integrator is at 0 or `N`.
+    fn is_settled(&self) -> bool {
+        self.integrator == 0 || self.integrator >= self.saturation()
+    }
+}
+
+impl_input_pin!(
+    impl<T: InputPin, A: Activeness, Th: Threshold> IntegratingDebouncer<T, A, Th>,
+    T::Error
+);
@@ -0,0 +1,192 @@
+//! An interrupt-driven debouncer fed with timestamped edges.
+
+use crate::sample::Sample;
+use crate::{Activeness, DebounceAt, DebounceState, Instant};
+use core::convert::Infallible;
+
+/// A debouncer driven by pin-change interrupts instead of polling.
+///
+/// The interrupt handler calls `on_edge()` with the time of the edge and the
+/// level the pin changed to. A change is debounced once the pin has kept its
+/// level for `window` since the last edge. Since no edge arrives to confirm
+/// that, a timer has to call `update_at()` at `next_deadline()`, and the
+/// processor can sleep in between.
+///
+/// A new debouncer starts `NotActive`. To report a pin which is already active
+/// at startup, call `sync()` with its level before enabling the interrupt.
+///
+/// By default an inactive edge releases the pin immediately. A release window
+/// set with `set_release_window()` debounces the release edge as well.
+pub struct InterruptDebouncer<A, I: Instant = u32> {
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The debounced pin state.
+    debounce_state: DebounceState,
+
+    /// When the pin last changed while it differs from the debounced state.
+    changed_at: Option<I>,
+
+    /// How long the pin has to stay active before it is debounced.
+    window: I::Duration,
+
+    /// How long the pin has to stay inactive before it is released.
+    release_window: I::Duration,
+}
+
+impl<A: Activeness, I: Instant> InterruptDebouncer<A, I> {
+    /// Initializes a new interrupt-driven debouncer.
+    ///
+    /// `window` is measured in the same units as the timestamps passed to
+    /// `on_edge()` and `update_at()`.
+    pub fn new(activeness: A, window: I::Duration) -> Self {
+        Self {
+            activeness,
+            debounce_state: DebounceState::NotActive,
+            changed_at: None,
+            window,
+            release_window: I::Duration::default(),
+        }
+    }
+
+    /// Returns how long the pin has to stay active before it is debounced.
+    pub fn window(&self) -> I::Duration {
+        self.window
+    }
+
+    /// Changes how long the pin has to stay active before it is debounced.
+    pub fn set_window(&mut self, window: I::Duration) {
+        self.window = window;
+    }
+
+    /// Returns how long the pin has to stay inactive before it is released.
+    pub fn release_window(&self) -> I::Duration {
+        self.release_window
+    }
+
+    /// Changes how long the pin has to stay inactive before it is released.
+    pub fn set_release_window(&mut self, release_window: I::Duration) {
+        self.release_window = release_window;
+    }
+
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Jumps straight to the debounced state of a pin which is `high` or low,
+    /// such as the level read at startup.
+    ///
+    /// The state becomes `Active` or `NotActive`, and a change being debounced
+    /// is dropped.
+    pub fn sync(&mut self, high: bool) -> DebounceState {
+        self.changed_at = None;
+        self.debounce_state = if self.is_active_level(high) {
+            DebounceState::Active
+        } else {
+            DebounceState::NotActive
+        };
+        self.debounce_state
+    }
+
+    /// Checks if a pin which is `high` or low is active.
+    fn is_active_level(&self, high: bool) -> bool {
+        self.activeness
+            .is_active(&mut Sample(high))
+            .unwrap_or_else(|never| match never {})
+    }
+
+    /// Records an edge of the pin at `now`, which left the pin `high` or low.
+    ///
+    /// Meant to be called from the pin-change interrupt handler.
+    pub fn on_edge(&mut self, now: I, high: bool) -> DebounceState {
+        let active = self.is_active_level(high);
+
+        if active == self.is_active() {
+            // The pin bounced back before the change was debounced.
+            self.changed_at = None;
+            self.debounce_state = if active {
+                DebounceState::Active
+            } else {
+                DebounceState::NotActive
+            };
+            return self.debounce_state;
+        }
+
+        self.changed_at = Some(now);
+        self.debounce_state = if active {
+            DebounceState::Debouncing
+        } else {
+            DebounceState::Releasing
+        };
+        self.settle(now)
+    }
+
+    /// Returns when `update_at()` has to be called to confirm a change, if a
+    /// change is being debounced.
+    pub fn next_deadline(&self) -> Option<I> {
+        self.changed_at
+            .map(|changed_at| changed_at.add_duration(self.current_window()))
+    }
+
+    /// Returns the window of the change being debounced.
+    fn current_window(&self) -> I::Duration {
+        if self.is_active() {
+            self.release_window
+        } else {
+            self.window
+        }
+    }
+
+    /// Confirms the change being debounced if the pin kept its level long enough.
+    fn settle(&mut self, now: I) -> DebounceState {
+        let changed_at = match self.changed_at {
+            Some(changed_at) => changed_at,
+            None => {
+                if self.debounce_state == DebounceState::Reset {
+                    self.debounce_state = DebounceState::NotActive;
+                }
+                return self.debounce_state;
+            }
+        };
+
+        if now.duration_since(changed_at) >= self.current_window() {
+            self.changed_at = None;
+            self.debounce_state = if self.is_active() {
+                DebounceState::Reset
+            } else {
+                DebounceState::Active
+            };
+        }
+
+        self.debounce_state
+    }
+}
+
+impl<A: Activeness, I: Instant> DebounceAt for InterruptDebouncer<A, I> {
+    type Error = Infallible;
+    type State = DebounceState;
+    type Instant = I;
+
+    /// Returns the debounce state from the last edge or update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
+    }
+
+    /// Confirms the change being debounced if `next_deadline()` has passed.
+    ///
+    /// Meant to be called from a timer set to `next_deadline()`.
+    fn update_at(&mut self, now: I) -> Result<Self::State, Self::Error> {
+        Ok(self.settle(now))
+    }
+
+    /// Checks if no change is being debounced, so no timer is needed.
+    fn is_settled(&self) -> bool {
+        self.changed_at.is_none()
+    }
+}
+
+impl_input_pin!(
+    impl<A: Activeness, I: Instant> InterruptDebouncer<A, I>,
+    Infallible
+);
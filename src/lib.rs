@@ -13,6 +13,18 @@
 //! The `InputPin` wrapper checks **only** the debounced state.
 //! It does not poll the pin and drives the debouncing poll implementation forward.
 //! To do this, you have to call `update()`. At best call it every 1ms in an ISR.
+//! `DebouncedInputPin::split()` lets the ISR own the pin while the main loop
+//! reads it without locking.
+//!
+//! # `embedded-hal` versions
+//!
+//! The debouncers wrap and implement the `embedded-hal` 1.0 `InputPin` trait.
+//! With the `embedded-hal-02` feature they also implement the 0.2
+//! `digital::v2::InputPin` trait, and 0.2 pins can be debounced by wrapping them
+//! in `hal02::Compat`.
+//!
+//! With the `async` feature, `AsyncDebouncer` implements the `embedded-hal-async`
+//! `Wait` trait, so a debounced pin can be awaited instead of polled.
 //!
 //! # Example
 //!
@@ -38,6 +50,10 @@
 //!         DebounceState::Debouncing => continue,
 //!         // Pin is active and debounced.
 //!         DebounceState::Active => break,
+//!         // Pin is still active but its release is debouncing.
+//!         DebounceState::Releasing => continue,
+//!         // Pin is ignored until its input has settled.
+//!         DebounceState::Initializing => continue,
 //!     }
 //!     // Wait to poll again in 1ms. Also hardware specific.
 //!     wait(1.ms());
@@ -54,10 +70,87 @@
 
 #![no_std]
 
+/// Implements `InputPin` for a debouncer by reporting its debounced state.
+///
+/// The debouncer needs an `is_active()` method and an `activeness` field. Also
+/// implements the `embedded-hal` 0.2 `InputPin` behind the `embedded-hal-02`
+/// feature.
+macro_rules! impl_input_pin {
+    (impl<$($param:ident $(: $bound:path)?),*> $ty:ty, $error:ty) => {
+        impl<$($param $(: $bound)?),*> embedded_hal::digital::ErrorType for $ty {
+            type Error = $error;
+        }
+
+        impl<$($param $(: $bound)?),*> embedded_hal::digital::InputPin for $ty {
+            fn is_high(&mut self) -> Result<bool, Self::Error> {
+                Ok(self.is_active() == self.activeness.is_high_when_active())
+            }
+
+            fn is_low(&mut self) -> Result<bool, Self::Error> {
+                Ok(self.is_active() != self.activeness.is_high_when_active())
+            }
+        }
+
+        #[cfg(feature = "embedded-hal-02")]
+        impl<$($param $(: $bound)?),*> embedded_hal_02::digital::v2::InputPin for $ty {
+            type Error = $error;
+
+            fn is_high(&self) -> Result<bool, Self::Error> {
+                Ok(self.is_active() == self.activeness.is_high_when_active())
+            }
+
+            fn is_low(&self) -> Result<bool, Self::Error> {
+                Ok(self.is_active() != self.activeness.is_high_when_active())
+            }
+        }
+    };
+}
+
 pub mod prelude;
 
-use core::marker::PhantomData;
-use embedded_hal::digital::v2::InputPin;
+#[cfg(feature = "embedded-hal-02")]
+pub mod hal02;
+
+mod array;
+mod builder;
+mod click;
+mod encoder;
+mod integrator;
+mod interrupt;
+mod lockout;
+mod long_press;
+mod matrix;
+mod port;
+mod repeat;
+mod sample;
+mod shift_register;
+mod split;
+mod threshold;
+mod time;
+mod timed;
+#[cfg(feature = "async")]
+mod wait;
+
+pub use array::{DebouncedPinArray, PinError};
+pub use builder::DebouncedInputPinBuilder;
+pub use click::{Click, ClickRecognizer};
+pub use encoder::{EncoderState, QuadratureEncoder, StepsPerDetent};
+pub use integrator::IntegratingDebouncer;
+pub use interrupt::InterruptDebouncer;
+pub use lockout::LockoutDebouncer;
+pub use long_press::{LongPress, PressEvent};
+pub use matrix::{GhostPolicy, KeyEvent, KeyMatrix, MatrixError};
+pub use port::{PortDebouncer, PortState, PortWord};
+pub use repeat::{AutoRepeat, RepeatEvent};
+pub use shift_register::{History, ShiftRegisterDebouncer};
+pub use split::{PinReader, PinUpdater, SharedPinState};
+pub use threshold::{ConstThreshold, RuntimeThreshold, Threshold};
+pub use time::Instant;
+pub use timed::TimedDebouncer;
+#[cfg(feature = "async")]
+pub use wait::{AlwaysPoll, AsyncDebouncer, Idle, PinWait};
+
+use embedded_hal::digital::InputPin;
 
 /// Unit struct for active-low pins.
 pub struct ActiveLow;
@@ -65,6 +158,84 @@ pub struct ActiveLow;
 /// Unit struct for active-high pins.
 pub struct ActiveHigh;
 
+/// Whether a pin is active-high or active-low, chosen at runtime.
+///
+/// Can be used in place of `ActiveHigh` and `ActiveLow` when the polarity is
+/// only known at runtime, such as after reading a strap pin at boot.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Polarity {
+    /// The pin is active when high.
+    ActiveHigh,
+    /// The pin is active when low.
+    ActiveLow,
+}
+
+/// What it means for a pin to be active.
+///
+/// Implemented by `ActiveHigh`, `ActiveLow` and `Polarity`. It can also be
+/// implemented for custom notions of active, and used as the `A` parameter of
+/// every debouncer in this crate.
+///
+/// ```rust
+/// use debounced_pin::Activeness;
+/// use embedded_hal::digital::InputPin;
+///
+/// /// Active while the pin differs from its level at boot.
+/// struct ChangedFromBoot {
+///     boot_level_high: bool,
+/// }
+///
+/// impl Activeness for ChangedFromBoot {
+///     fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error> {
+///         Ok(pin.is_high()? != self.boot_level_high)
+///     }
+///
+///     fn is_high_when_active(&self) -> bool {
+///         !self.boot_level_high
+///     }
+/// }
+/// ```
+pub trait Activeness {
+    /// Checks if the pin is active.
+    fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error>;
+
+    /// Checks if a debounced pin reads high while it is active.
+    fn is_high_when_active(&self) -> bool;
+}
+
+impl Activeness for ActiveHigh {
+    fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error> {
+        pin.is_high()
+    }
+
+    fn is_high_when_active(&self) -> bool {
+        true
+    }
+}
+
+impl Activeness for ActiveLow {
+    fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error> {
+        pin.is_low()
+    }
+
+    fn is_high_when_active(&self) -> bool {
+        false
+    }
+}
+
+impl Activeness for Polarity {
+    fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error> {
+        match self {
+            Polarity::ActiveHigh => pin.is_high(),
+            Polarity::ActiveLow => pin.is_low(),
+        }
+    }
+
+    fn is_high_when_active(&self) -> bool {
+        *self == Polarity::ActiveHigh
+    }
+}
+
 /// The debounce state of the `update()` method.
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum DebounceState {
@@ -76,6 +247,19 @@ pub enum DebounceState {
     NotActive,
     /// The pin state is active and debounced.
     Active,
+    /// The pin state is not active, but the release is still debouncing.
+    Releasing,
+    /// The pin is ignored during the startup blanking period.
+    Initializing,
+}
+
+impl DebounceState {
+    /// Checks if the state reports the pin as debounced active.
+    ///
+    /// This is the case for `Active` and `Releasing`.
+    pub fn is_active(self) -> bool {
+        matches!(self, DebounceState::Active | DebounceState::Releasing)
+    }
 }
 
 /// A debounced input pin.
@@ -84,18 +268,72 @@ pub enum DebounceState {
 /// ([archived 2018-09-03](https://web.archive.org/web/20180903142143/http://www.labbookpages.co.uk/electronics/debounce.html#soft)).
 ///
 /// Requires `update()` to be called every ~1ms.
-pub struct DebouncedInputPin<T: InputPin, A> {
+///
+/// The pin is considered debounced once it has been active for a threshold of
+/// consecutive calls to `update()`. The threshold is given by `Th`, which
+/// defaults to `ConstThreshold<10>`. Pins created with `builder()` have a
+/// `RuntimeThreshold` instead, which can be changed with `set_threshold()`.
+///
+/// By default a single inactive sample releases the pin. A release threshold set
+/// with `set_release_threshold()` debounces the release edge as well.
+///
+/// A blanking period set with `set_blanking()` ignores the pin for a number of
+/// ticks after construction and after `reset()`, while inputs are still settling.
+/// Set before the first `update()`, it also blanks a pin created with `new()`.
+pub struct DebouncedInputPin<T: InputPin, A, Th = ConstThreshold<10>> {
     /// The wrapped pin.
     pub pin: T,
 
     /// Whether the pin is active-high or active-low.
-    activeness: PhantomData<A>,
+    activeness: A,
 
     /// The debounced pin state.
     debounce_state: DebounceState,
 
     /// The counter.
-    counter: i8,
+    counter: u16,
+
+    /// The number of active ticks required before the pin is debounced.
+    threshold: Th,
+
+    /// The release counter.
+    release_counter: u16,
+
+    /// The number of inactive ticks required before the pin is released.
+    release_threshold: u16,
+
+    /// The number of ticks the pin is ignored for after a reset.
+    blanking: u16,
+
+    /// The number of ticks the pin is still ignored for.
+    blanking_left: u16,
+
+    /// Whether the pin has been read since construction or the last reset.
+    updated: bool,
+
+    /// Whether the pin was reset while active, so the next update reports a
+    /// release.
+    release_pending: bool,
+}
+
+/// A debounced transition of a pin.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Edge {
+    /// The pin became debounced active.
+    Pressed,
+    /// The pin stopped being debounced active.
+    Released,
+}
+
+impl Edge {
+    /// Returns the edge between two consecutive debounce states, if any.
+    pub fn between(previous: DebounceState, next: DebounceState) -> Option<Self> {
+        match (previous.is_active(), next.is_active()) {
+            (false, true) => Some(Edge::Pressed),
+            (true, false) => Some(Edge::Released),
+            _ => None,
+        }
+    }
 }
 
 /// Debounce Trait which provides an `update()` method which debounces the pin.
@@ -103,103 +341,318 @@ pub trait Debounce {
     type Error;
     type State;
 
+    fn state(&self) -> Self::State;
+
     fn update(&mut self) -> Result<Self::State, Self::Error>;
+
+    /// Checks if the debounce logic is idle, so `update()` can stop being
+    /// called until the pin changes.
+    ///
+    /// False while a change is being debounced or a timed window is running.
+    fn is_settled(&self) -> bool;
+
+    /// Updates the debounce logic and returns the edge it caused, if any.
+    ///
+    /// Fires exactly once per debounced transition.
+    fn update_events(&mut self) -> Result<Option<Edge>, Self::Error>
+    where
+        Self: Sized + Debounce<State = DebounceState>,
+    {
+        let previous = self.state();
+        let next = self.update()?;
+        Ok(Edge::between(previous, next))
+    }
+}
+
+impl<D: Debounce + ?Sized> Debounce for &mut D {
+    type Error = D::Error;
+    type State = D::State;
+
+    fn state(&self) -> Self::State {
+        (**self).state()
+    }
+
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        (**self).update()
+    }
+
+    fn is_settled(&self) -> bool {
+        (**self).is_settled()
+    }
+}
+
+/// Time-aware counterpart of `Debounce` which provides an `update_at()` method
+/// taking the current time.
+pub trait DebounceAt {
+    type Error;
+    type State;
+    type Instant: Instant;
+
+    fn state(&self) -> Self::State;
+
+    fn update_at(&mut self, now: Self::Instant) -> Result<Self::State, Self::Error>;
+
+    /// Checks if the debounce logic is idle, so `update_at()` can stop being
+    /// called until the pin changes.
+    fn is_settled(&self) -> bool;
+
+    /// Updates the debounce logic at the given time and returns the edge it
+    /// caused, if any.
+    fn update_events_at(&mut self, now: Self::Instant) -> Result<Option<Edge>, Self::Error>
+    where
+        Self: Sized + DebounceAt<State = DebounceState>,
+    {
+        let previous = self.state();
+        let next = self.update_at(now)?;
+        Ok(Edge::between(previous, next))
+    }
 }
 
 impl<T: InputPin, A> DebouncedInputPin<T, A> {
     /// Initializes a new debounced input pin.
-    pub fn new(pin: T, _activeness: A) -> Self {
+    pub fn new(pin: T, activeness: A) -> Self {
+        Self::new_with_threshold(pin, activeness)
+    }
+
+    /// Creates a builder for a debounced input pin with runtime configuration.
+    ///
+    /// The built pin has a `RuntimeThreshold`, which defaults to 10.
+    ///
+    /// ```rust,ignore
+    /// let pin = DebouncedInputPin::builder(pin, ActiveHigh)
+    ///     .threshold(25)
+    ///     .build();
+    /// ```
+    pub fn builder(pin: T, activeness: A) -> DebouncedInputPinBuilder<T, A> {
+        DebouncedInputPinBuilder::new(pin, activeness)
+    }
+}
+
+impl<T: InputPin, A: Activeness, const N: u16> DebouncedInputPin<T, A, ConstThreshold<N>> {
+    /// Initializes a new debounced input pin with a threshold of `N` ticks which
+    /// starts in the pin's current state.
+    ///
+    /// A pin which is already active reads as debounced active right away,
+    /// instead of only after the threshold.
+    ///
+    /// ```rust,ignore
+    /// let pin = DebouncedInputPin::<_, _>::new_synced(pin, ActiveHigh)?;
+    /// let pin = DebouncedInputPin::<_, _, ConstThreshold<50>>::new_synced(pin, ActiveHigh)?;
+    /// ```
+    pub fn new_synced(pin: T, activeness: A) -> Result<Self, T::Error> {
+        let mut pin = Self::new_with_threshold(pin, activeness);
+        pin.sync()?;
+        Ok(pin)
+    }
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> DebouncedInputPin<T, A, Th> {
+    /// Reads the pin and jumps straight to its debounced state.
+    ///
+    /// The state becomes `Active` or `NotActive`, with the counter set to match.
+    /// Ends the blanking period, if any.
+    pub fn sync(&mut self) -> Result<DebounceState, T::Error> {
+        let active = self.activeness.is_active(&mut self.pin)?;
+        self.release_counter = 0;
+        self.blanking_left = 0;
+        self.updated = true;
+        if active {
+            self.counter = self.threshold.ticks();
+            self.debounce_state = DebounceState::Active;
+        } else {
+            self.counter = 0;
+            self.debounce_state = DebounceState::NotActive;
+        }
+
+        Ok(self.debounce_state)
+    }
+}
+
+impl<T: InputPin, A, const N: u16> DebouncedInputPin<T, A, ConstThreshold<N>> {
+    /// Initializes a new debounced input pin with a threshold of `N` ticks.
+    ///
+    /// ```rust,ignore
+    /// let pin =
+    ///     DebouncedInputPin::<_, _, ConstThreshold<50>>::new_with_threshold(pin, ActiveHigh);
+    /// ```
+    pub fn new_with_threshold(pin: T, activeness: A) -> Self {
+        Self::with_threshold(pin, activeness, ConstThreshold)
+    }
+}
+
+impl<T: InputPin, A> DebouncedInputPin<T, A, RuntimeThreshold> {
+    /// Changes the number of active ticks required before the pin is debounced.
+    ///
+    /// The counter is clamped to the new threshold, and an already debounced
+    /// pin stays active.
+    pub fn set_threshold(&mut self, threshold: u16) {
+        self.threshold = RuntimeThreshold(threshold);
+        if self.debounce_state == DebounceState::Active || self.counter > threshold {
+            self.counter = threshold;
+        }
+    }
+}
+
+impl<T: InputPin, A, Th: Threshold> DebouncedInputPin<T, A, Th> {
+    /// Initializes a new debounced input pin with the given threshold.
+    pub(crate) fn with_threshold(pin: T, activeness: A, threshold: Th) -> Self {
         Self {
             pin,
-            activeness: PhantomData,
+            activeness,
             counter: 0,
+            threshold,
+            release_counter: 0,
+            release_threshold: 0,
+            blanking: 0,
+            blanking_left: 0,
+            updated: false,
+            release_pending: false,
             debounce_state: DebounceState::NotActive,
         }
     }
 
-    /// Checks if the pin debounce state is active.
-    pub fn is_active(&self) -> bool {
-        self.debounce_state == DebounceState::Active
+    /// Returns the number of ticks the pin is ignored for after a reset.
+    pub fn blanking(&self) -> u16 {
+        self.blanking
     }
-}
 
-impl<T: InputPin> Debounce for DebouncedInputPin<T, ActiveHigh> {
-    type Error = T::Error;
-    type State = DebounceState;
+    /// Changes the number of ticks the pin is ignored for after a reset.
+    ///
+    /// Called before the first `update()` after construction or a `reset()`,
+    /// the blanking period starts right away. Otherwise it takes effect on the
+    /// next `reset()`.
+    pub fn set_blanking(&mut self, blanking: u16) {
+        self.blanking = blanking;
+        if !self.updated {
+            self.reset();
+        }
+    }
 
-    /// Updates the debounce logic.
+    /// Starts over as if the pin was just constructed, including the blanking period.
     ///
-    /// Needs to be called every ~1ms.
-    fn update(&mut self) -> Result<Self::State, Self::Error> {
-        if self.pin.is_low()? {
-            if self.debounce_state == Self::State::Active {
-                self.counter = 0;
-                self.debounce_state = Self::State::Reset;
-            } else {
-                self.debounce_state = Self::State::NotActive;
-            }
-        } else if self.counter < 10 {
-            self.counter += 1;
-            self.debounce_state = Self::State::Debouncing;
+    /// A pin which was active reports `Edge::Released` from the next
+    /// `update_events()`.
+    pub fn reset(&mut self) {
+        self.release_pending |= self.is_active();
+        self.updated = false;
+        self.counter = 0;
+        self.release_counter = 0;
+        self.blanking_left = self.blanking;
+        self.debounce_state = if self.blanking > 0 {
+            DebounceState::Initializing
         } else {
-            // Max count is reached
-            self.debounce_state = Self::State::Active;
-        }
+            DebounceState::NotActive
+        };
+    }
 
-        Ok(self.debounce_state)
+    /// Returns the number of active ticks required before the pin is debounced.
+    pub fn threshold(&self) -> u16 {
+        self.threshold.ticks()
     }
-}
 
-impl<T: InputPin> Debounce for DebouncedInputPin<T, ActiveLow> {
-    type Error = T::Error;
-    type State = DebounceState;
+    /// Returns the number of inactive ticks required before the pin is released.
+    pub fn release_threshold(&self) -> u16 {
+        self.release_threshold
+    }
 
-    /// Updates the debounce logic.
+    /// Changes the number of inactive ticks required before the pin is released.
     ///
-    /// Needs to be called every ~1ms.
-    fn update(&mut self) -> Result<Self::State, Self::Error> {
-        if self.pin.is_high()? {
-            if self.debounce_state == Self::State::Active {
-                self.counter = 0;
-                self.debounce_state = Self::State::Reset;
-            } else {
-                self.debounce_state = Self::State::NotActive;
+    /// A release threshold of 0 releases the pin on the first inactive sample.
+    pub fn set_release_threshold(&mut self, release_threshold: u16) {
+        self.release_threshold = release_threshold;
+        if self.release_counter > release_threshold {
+            self.release_counter = release_threshold;
+        }
+    }
+
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Drives the debounce logic with a sample of whether the pin is active.
+    fn debounce(&mut self, active: bool) -> DebounceState {
+        self.debounce_state = match self.debounce_state {
+            DebounceState::Active | DebounceState::Releasing if active => {
+                self.release_counter = 0;
+                DebounceState::Active
+            }
+            DebounceState::Active | DebounceState::Releasing => {
+                if self.release_counter < self.release_threshold {
+                    self.release_counter += 1;
+                    DebounceState::Releasing
+                } else {
+                    self.counter = 0;
+                    self.release_counter = 0;
+                    DebounceState::Reset
+                }
+            }
+            _ if !active => DebounceState::NotActive,
+            _ if self.counter < self.threshold.ticks() => {
+                self.counter += 1;
+                DebounceState::Debouncing
             }
-        } else if self.counter < 10 {
-            self.counter += 1;
-            self.debounce_state = Self::State::Debouncing;
-        } else {
             // Max count is reached
-            self.debounce_state = Self::State::Active;
-        }
+            _ => DebounceState::Active,
+        };
 
-        Ok(self.debounce_state)
+        self.debounce_state
     }
 }
 
-impl<T: InputPin> InputPin for DebouncedInputPin<T, ActiveHigh> {
+impl<T: InputPin, A: Activeness, Th: Threshold> Debounce for DebouncedInputPin<T, A, Th> {
     type Error = T::Error;
+    type State = DebounceState;
 
-    fn is_high(&self) -> Result<bool, Self::Error> {
-        Ok(self.debounce_state == DebounceState::Active)
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
     }
 
-    fn is_low(&self) -> Result<bool, Self::Error> {
-        Ok(self.debounce_state != DebounceState::Active)
-    }
-}
+    /// Updates the debounce logic.
+    ///
+    /// The pin is not read during the blanking period. Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.updated = true;
+        if self.blanking_left > 0 {
+            self.blanking_left -= 1;
+            self.debounce_state = DebounceState::Initializing;
+            return Ok(self.debounce_state);
+        }
 
-impl<T: InputPin> InputPin for DebouncedInputPin<T, ActiveLow> {
-    type Error = T::Error;
+        let active = self.activeness.is_active(&mut self.pin)?;
+        Ok(self.debounce(active))
+    }
 
-    fn is_high(&self) -> Result<bool, Self::Error> {
-        Ok(self.debounce_state != DebounceState::Active)
+    /// Checks if the pin is neither debouncing, releasing nor blanked, and has
+    /// no release from a `reset()` left to report.
+    fn is_settled(&self) -> bool {
+        !self.release_pending
+            && matches!(
+                self.debounce_state,
+                DebounceState::NotActive | DebounceState::Reset | DebounceState::Active
+            )
     }
 
-    fn is_low(&self) -> Result<bool, Self::Error> {
-        Ok(self.debounce_state == DebounceState::Active)
+    /// Updates the debounce logic and returns the edge it caused, if any.
+    ///
+    /// Reports `Edge::Released` after a `reset()` of an active pin.
+    fn update_events(&mut self) -> Result<Option<Edge>, Self::Error> {
+        let previous = if self.release_pending {
+            DebounceState::Active
+        } else {
+            self.debounce_state
+        };
+        let next = self.update()?;
+        self.release_pending = false;
+        Ok(Edge::between(previous, next))
     }
 }
 
+impl_input_pin!(
+    impl<T: InputPin, A: Activeness, Th: Threshold> DebouncedInputPin<T, A, Th>,
+    T::Error
+);
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,121 @@
+//! A lockout ("react-immediately") debouncer.
+
+use crate::{Activeness, ConstThreshold, Debounce, DebounceState, Threshold};
+use embedded_hal::digital::InputPin;
+
+/// An input pin debounced by a lockout window.
+///
+/// The first changed sample is reported immediately, after which the pin is
+/// ignored for a lockout window of `N` calls to `update()` so that the bounces
+/// following the edge are not seen. The window is given by `Th`, which defaults
+/// to `ConstThreshold<10>`.
+///
+/// Requires `update()` to be called every ~1ms.
+pub struct LockoutDebouncer<T: InputPin, A, Th = ConstThreshold<10>> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The number of ticks the pin is ignored for after an edge.
+    threshold: Th,
+
+    /// The debounced pin state.
+    debounce_state: DebounceState,
+
+    /// The number of ticks the pin is still ignored for.
+    lockout: u16,
+}
+
+impl<T: InputPin, A> LockoutDebouncer<T, A> {
+    /// Initializes a new lockout debouncer.
+    pub fn new(pin: T, activeness: A) -> Self {
+        Self::new_with_threshold(pin, activeness)
+    }
+}
+
+impl<T: InputPin, A, const N: u16> LockoutDebouncer<T, A, ConstThreshold<N>> {
+    /// Initializes a new lockout debouncer with a lockout window of `N` ticks.
+    pub fn new_with_threshold(pin: T, activeness: A) -> Self {
+        Self {
+            pin,
+            activeness,
+            threshold: ConstThreshold,
+            debounce_state: DebounceState::NotActive,
+            lockout: 0,
+        }
+    }
+}
+
+impl<T: InputPin, A, Th: Threshold> LockoutDebouncer<T, A, Th> {
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Checks if the pin is currently ignored.
+    pub fn is_locked_out(&self) -> bool {
+        self.lockout > 0
+    }
+
+    /// Reports the steady state while the pin is ignored or unchanged.
+    fn hold(&mut self) -> DebounceState {
+        self.debounce_state = if self.is_active() {
+            DebounceState::Active
+        } else {
+            DebounceState::NotActive
+        };
+
+        self.debounce_state
+    }
+
+    /// Drives the lockout logic with a sample of whether the pin is active.
+    fn sample(&mut self, active: bool) -> DebounceState {
+        if active == self.is_active() {
+            return self.hold();
+        }
+
+        self.lockout = self.threshold.ticks();
+        self.debounce_state = if active {
+            DebounceState::Active
+        } else {
+            DebounceState::Reset
+        };
+
+        self.debounce_state
+    }
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> Debounce for LockoutDebouncer<T, A, Th> {
+    type Error = T::Error;
+    type State = DebounceState;
+
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
+    }
+
+    /// Updates the lockout logic.
+    ///
+    /// The pin is not read while it is locked out. Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        if self.lockout > 0 {
+            self.lockout -= 1;
+            return Ok(self.hold());
+        }
+
+        let active = self.activeness.is_active(&mut self.pin)?;
+        Ok(self.sample(active))
+    }
+
+    /// Checks if the pin is not locked out.
+    fn is_settled(&self) -> bool {
+        !self.is_locked_out()
+    }
+}
+
+impl_input_pin!(
+    impl<T: InputPin, A: Activeness, Th: Threshold> LockoutDebouncer<T, A, Th>,
+    T::Error
+);
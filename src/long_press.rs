@@ -0,0 +1,106 @@
+//! Long-press detection on top of a debounced pin.
+
+use crate::{Debounce, DebounceState, Edge};
+
+/// A press event reported by `LongPress`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PressEvent {
+    /// The pin was released before the long-press threshold.
+    ShortPress,
+    /// The pin has been held for the long-press threshold and is still held.
+    LongPressStart,
+    /// The pin was released after a long press.
+    LongPressRelease,
+}
+
+/// Tells short presses and long presses of a debounced pin apart.
+///
+/// Wraps anything implementing `Debounce` with `DebounceState`, such as a
+/// `DebouncedInputPin`. The threshold is measured in calls to `update()`, so
+/// with `update()` called every ~1ms a threshold of 1000 is one second.
+pub struct LongPress<D> {
+    /// The wrapped debounced pin.
+    pub pin: D,
+
+    /// The number of ticks a press has to be held to be a long press.
+    threshold: u16,
+
+    /// The number of ticks the current press has been held for.
+    held: u16,
+
+    /// Whether the current press is a long press.
+    long: bool,
+
+    /// The event from the last update.
+    event: Option<PressEvent>,
+}
+
+impl<D: Debounce<State = DebounceState>> LongPress<D> {
+    /// Initializes a new long-press detector.
+    pub fn new(pin: D, threshold: u16) -> Self {
+        Self {
+            pin,
+            threshold,
+            held: 0,
+            long: false,
+            event: None,
+        }
+    }
+
+    /// Returns the number of ticks a press has to be held to be a long press.
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Changes the number of ticks a press has to be held to be a long press.
+    pub fn set_threshold(&mut self, threshold: u16) {
+        self.threshold = threshold;
+    }
+
+    /// Checks if the current press has been held past the threshold.
+    pub fn is_long_press(&self) -> bool {
+        self.long
+    }
+}
+
+impl<D: Debounce<State = DebounceState>> Debounce for LongPress<D> {
+    type Error = D::Error;
+    type State = Option<PressEvent>;
+
+    /// Returns the event from the last update.
+    fn state(&self) -> Self::State {
+        self.event
+    }
+
+    /// Updates the wrapped pin and the press duration.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.event = match self.pin.update_events()? {
+            Some(Edge::Pressed) => {
+                self.held = 0;
+                self.long = false;
+                None
+            }
+            Some(Edge::Released) if self.long => Some(PressEvent::LongPressRelease),
+            Some(Edge::Released) => Some(PressEvent::ShortPress),
+            None if self.pin.state().is_active() && !self.long => {
+                self.held += 1;
+                if self.held >= self.threshold {
+                    self.long = true;
+                    Some(PressEvent::LongPressStart)
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+
+        Ok(self.event)
+    }
+
+    /// Checks if the wrapped pin is settled and no press is being timed.
+    fn is_settled(&self) -> bool {
+        self.pin.is_settled() && (self.long || !self.pin.state().is_active())
+    }
+}
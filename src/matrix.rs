@@ -0,0 +1,228 @@
+//! A keyboard matrix scanner with per-key debouncing.
+
+use crate::sample::Sample;
+use crate::{ActiveHigh, Debounce, DebounceState, DebouncedInputPin, Edge, RuntimeThreshold};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{InputPin, OutputPin};
+
+/// A debounced change of a key in a `KeyMatrix`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct KeyEvent {
+    /// The row of the key.
+    pub row: usize,
+    /// The column of the key.
+    pub col: usize,
+    /// Whether the key went down or up.
+    pub edge: Edge,
+    /// Whether the key may be a ghost, see `KeyMatrix::is_ghost()`.
+    pub ghost: bool,
+}
+
+/// An error while scanning a `KeyMatrix`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MatrixError<RE, CE> {
+    /// Driving a row pin failed.
+    Row(RE),
+    /// Reading a column pin failed.
+    Column(CE),
+}
+
+/// What a `KeyMatrix` does with keys which may be ghosts.
+///
+/// When three keys forming the corners of a rectangle are down on a matrix
+/// without diodes, the fourth corner reads as down as well. All four keys are
+/// then ambiguous, since any of them could be the ghost.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GhostPolicy {
+    /// Report ambiguous keys like any other key, and flag them in
+    /// `KeyEvent::ghost` and `is_ghost()`.
+    Flag,
+    /// Hold back changes of ambiguous keys until they are no longer ambiguous.
+    Suppress,
+}
+
+/// A scanner for a matrix of `ROWS` by `COLS` keys.
+///
+/// Rows are driven low one at a time, and a key is down when its column reads
+/// low while its row is driven, so columns need pull-ups. Every row is driven
+/// high on the first scan, and left high between scans. Every key is debounced
+/// by the same logic as `DebouncedInputPin::update()`, with one sample per scan.
+///
+/// Keys which may be ghosts are handled according to the `GhostPolicy`, which
+/// defaults to `GhostPolicy::Flag`.
+///
+/// Requires `scan()` to be called every ~1ms.
+pub struct KeyMatrix<R, C, const ROWS: usize, const COLS: usize> {
+    /// The row pins.
+    pub rows: [R; ROWS],
+
+    /// The column pins.
+    pub cols: [C; COLS],
+
+    /// The debounce logic of every key.
+    keys: [[DebouncedInputPin<Sample, ActiveHigh, RuntimeThreshold>; COLS]; ROWS],
+
+    /// Whether every key was last reported down.
+    reported: [[bool; COLS]; ROWS],
+
+    /// Whether every key was ambiguous in the last scan.
+    ghosts: [[bool; COLS]; ROWS],
+
+    /// What to do with ambiguous keys.
+    ghost_policy: GhostPolicy,
+
+    /// The microseconds to wait between driving a row and sampling the columns.
+    settle_us: u32,
+
+    /// Whether every row has been driven high.
+    rows_idle: bool,
+}
+
+impl<R: OutputPin, C: InputPin, const ROWS: usize, const COLS: usize> KeyMatrix<R, C, ROWS, COLS> {
+    /// Initializes a new matrix scanner with every key up.
+    pub fn new(rows: [R; ROWS], cols: [C; COLS]) -> Self {
+        Self {
+            rows,
+            cols,
+            keys: core::array::from_fn(|_| {
+                core::array::from_fn(|_| {
+                    DebouncedInputPin::builder(Sample(false), ActiveHigh).build()
+                })
+            }),
+            reported: [[false; COLS]; ROWS],
+            ghosts: [[false; COLS]; ROWS],
+            ghost_policy: GhostPolicy::Flag,
+            settle_us: 0,
+            rows_idle: false,
+        }
+    }
+
+    /// Changes what to do with keys which may be ghosts.
+    pub fn set_ghost_policy(&mut self, ghost_policy: GhostPolicy) {
+        self.ghost_policy = ghost_policy;
+    }
+
+    /// Changes the number of scans a key has to be down before it is debounced.
+    pub fn set_threshold(&mut self, threshold: u16) {
+        for key in self.keys.iter_mut().flatten() {
+            key.set_threshold(threshold);
+        }
+    }
+
+    /// Changes the microseconds to wait between driving a row and sampling the columns.
+    pub fn set_settle_us(&mut self, settle_us: u32) {
+        self.settle_us = settle_us;
+    }
+
+    /// Returns the debounce state of a key from the last scan.
+    pub fn key_state(&self, row: usize, col: usize) -> DebounceState {
+        self.keys[row][col].state()
+    }
+
+    /// Checks if a key was last reported down.
+    pub fn is_down(&self, row: usize, col: usize) -> bool {
+        self.reported[row][col]
+    }
+
+    /// Checks if a key was ambiguous in the last scan, so it may be a ghost.
+    pub fn is_ghost(&self, row: usize, col: usize) -> bool {
+        self.ghosts[row][col]
+    }
+
+    /// Checks if no key is being debounced, so `scan()` can stop being called
+    /// until a key changes.
+    pub fn is_settled(&self) -> bool {
+        self.keys.iter().flatten().all(|key| key.is_settled())
+    }
+
+    /// Marks every debounced down key which is a corner of a rectangle of
+    /// debounced down keys as ambiguous.
+    fn detect_ghosts(&mut self) {
+        self.ghosts = [[false; COLS]; ROWS];
+        for first in 0..ROWS {
+            for second in first + 1..ROWS {
+                let shared = (0..COLS)
+                    .filter(|&col| {
+                        self.keys[first][col].is_active() && self.keys[second][col].is_active()
+                    })
+                    .count();
+                if shared < 2 {
+                    continue;
+                }
+
+                for col in 0..COLS {
+                    if self.keys[first][col].is_active() && self.keys[second][col].is_active() {
+                        self.ghosts[first][col] = true;
+                        self.ghosts[second][col] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scans every key once and calls `on_event` for every debounced change.
+    ///
+    /// With `GhostPolicy::Suppress`, changes of ambiguous keys are not reported.
+    ///
+    /// Needs to be called every ~1ms.
+    pub fn scan<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        mut on_event: impl FnMut(KeyEvent),
+    ) -> Result<(), MatrixError<R::Error, C::Error>> {
+        if !self.rows_idle {
+            for row_pin in self.rows.iter_mut() {
+                row_pin.set_high().map_err(MatrixError::Row)?;
+            }
+            self.rows_idle = true;
+        }
+
+        for (row, row_pin) in self.rows.iter_mut().enumerate() {
+            row_pin.set_low().map_err(MatrixError::Row)?;
+            if self.settle_us > 0 {
+                delay.delay_us(self.settle_us);
+            }
+
+            let mut sampled = Ok(());
+            for (col, col_pin) in self.cols.iter_mut().enumerate() {
+                let key = &mut self.keys[row][col];
+                match col_pin.is_low() {
+                    Ok(low) => key.pin.0 = low,
+                    Err(e) => {
+                        sampled = Err(MatrixError::Column(e));
+                        break;
+                    }
+                }
+                key.update().unwrap_or_else(|never| match never {});
+            }
+
+            // Release the row before reporting a column error, so it does not
+            // stay selected.
+            row_pin.set_high().map_err(MatrixError::Row)?;
+            sampled?;
+        }
+
+        self.detect_ghosts();
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                if self.ghost_policy == GhostPolicy::Suppress && self.ghosts[row][col] {
+                    continue;
+                }
+
+                let down = self.keys[row][col].is_active();
+                if down != self.reported[row][col] {
+                    self.reported[row][col] = down;
+                    let edge = if down { Edge::Pressed } else { Edge::Released };
+                    on_event(KeyEvent {
+                        row,
+                        col,
+                        edge,
+                        ghost: self.ghosts[row][col],
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
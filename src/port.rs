@@ -0,0 +1,116 @@
+//! A port-level debouncer using vertical counters.
+
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// An integer holding one GPIO port's worth of pins, one per bit.
+///
+/// Implemented for `u8`, `u16` and `u32`.
+pub trait PortWord:
+    Copy
+    + Eq
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+    /// A word with every bit cleared.
+    const ZERO: Self;
+}
+
+macro_rules! impl_port_word {
+    ($($t:ty),*) => {
+        $(
+            impl PortWord for $t {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_port_word!(u8, u16, u32);
+
+/// The result of `PortDebouncer::update()`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PortState<W> {
+    /// The bits which are debounced active.
+    pub stable: W,
+    /// The bits which became debounced active during this update.
+    pub pressed: W,
+    /// The bits which stopped being debounced active during this update.
+    pub released: W,
+}
+
+/// Debounces every pin of a GPIO port at once.
+///
+/// Implements Peter Dannegger's vertical counter debouncer. Each bit has its
+/// own 2-bit counter, spread over two words, so a bit changes its debounced
+/// state after 4 consecutive samples which differ from it. All bits are
+/// debounced with a handful of bitwise operations per `update()`.
+///
+/// Bits set in the active-low mask are active when low, replacing the
+/// `ActiveHigh` and `ActiveLow` markers of the single pin debouncers.
+///
+/// Requires `update()` to be called every ~1ms.
+pub struct PortDebouncer<W: PortWord> {
+    /// The bits which are active when low.
+    active_low: W,
+
+    /// The debounced state, one bit per pin.
+    stable: W,
+
+    /// The low bits of the vertical counters.
+    ct0: W,
+
+    /// The high bits of the vertical counters.
+    ct1: W,
+}
+
+impl<W: PortWord> PortDebouncer<W> {
+    /// Initializes a new port debouncer with every bit not active.
+    pub fn new(active_low: W) -> Self {
+        Self {
+            active_low,
+            stable: W::ZERO,
+            ct0: !W::ZERO,
+            ct1: !W::ZERO,
+        }
+    }
+
+    /// Returns the bits which are active when low.
+    pub fn active_low(&self) -> W {
+        self.active_low
+    }
+
+    /// Returns the bits which are debounced active.
+    pub fn stable(&self) -> W {
+        self.stable
+    }
+
+    /// Checks if no bit is being debounced, so `update()` can stop being called
+    /// until the port changes.
+    pub fn is_settled(&self) -> bool {
+        self.ct0 & self.ct1 == !W::ZERO
+    }
+
+    /// Updates the debounce logic with a sample of the whole port.
+    ///
+    /// Needs to be called every ~1ms.
+    pub fn update(&mut self, sample: W) -> PortState<W> {
+        let delta = (sample ^ self.active_low) ^ self.stable;
+
+        // Count down every bit which differs from the debounced state, and reset
+        // the counter of every bit which does not.
+        self.ct0 = !(self.ct0 & delta);
+        self.ct1 = self.ct0 ^ (self.ct1 & delta);
+
+        // Bits whose counter rolled over change their debounced state.
+        let toggle = delta & self.ct0 & self.ct1;
+        self.stable = self.stable ^ toggle;
+
+        PortState {
+            stable: self.stable,
+            pressed: toggle & self.stable,
+            released: toggle & !self.stable,
+        }
+    }
+}
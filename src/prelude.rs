@@ -1,5 +1,7 @@
 //! Import the needed types and traits to use the `update()` method.
 
 pub use crate::Debounce;
+pub use crate::DebounceAt;
 pub use crate::DebounceState;
 pub use crate::DebouncedInputPin;
+pub use crate::Edge;
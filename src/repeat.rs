@@ -0,0 +1,127 @@
+//! Auto-repeat (typematic) events on top of a debounced pin.
+
+use crate::{Debounce, DebounceState, Edge};
+
+/// An event reported by `AutoRepeat`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RepeatEvent {
+    /// The pin was pressed.
+    Pressed,
+    /// The pin is still held and the press repeats.
+    Repeat,
+}
+
+/// Repeats a press of a debounced pin while it is held, like a keyboard.
+///
+/// Wraps anything implementing `Debounce` with `DebounceState`, such as a
+/// `DebouncedInputPin`. A press is reported once, then repeated every `interval`
+/// ticks after an initial `delay`. With acceleration enabled, the interval
+/// shrinks by a step after every repeat until it reaches a minimum.
+pub struct AutoRepeat<D> {
+    /// The wrapped debounced pin.
+    pub pin: D,
+
+    /// The number of ticks from a press to the first repeat.
+    delay: u16,
+
+    /// The number of ticks between the first repeats.
+    interval: u16,
+
+    /// The number of ticks the interval shrinks by after every repeat.
+    acceleration: u16,
+
+    /// The smallest number of ticks between repeats.
+    min_interval: u16,
+
+    /// The number of ticks between the current repeats.
+    current_interval: u16,
+
+    /// The number of ticks until the next repeat.
+    countdown: u16,
+
+    /// The event from the last update.
+    event: Option<RepeatEvent>,
+}
+
+impl<D: Debounce<State = DebounceState>> AutoRepeat<D> {
+    /// Initializes a new auto-repeat wrapper without acceleration.
+    pub fn new(pin: D, delay: u16, interval: u16) -> Self {
+        Self {
+            pin,
+            delay,
+            interval,
+            acceleration: 0,
+            min_interval: interval,
+            current_interval: interval,
+            countdown: 0,
+            event: None,
+        }
+    }
+
+    /// Changes the number of ticks from a press to the first repeat.
+    pub fn set_delay(&mut self, delay: u16) {
+        self.delay = delay;
+    }
+
+    /// Changes the number of ticks between the first repeats.
+    ///
+    /// Acceleration never grows the interval, even above a new minimum.
+    pub fn set_interval(&mut self, interval: u16) {
+        self.interval = interval;
+    }
+
+    /// Shrinks the interval by `step` ticks after every repeat, down to `min_interval`.
+    ///
+    /// A step of 0 disables acceleration.
+    pub fn set_acceleration(&mut self, step: u16, min_interval: u16) {
+        self.acceleration = step;
+        self.min_interval = min_interval;
+    }
+}
+
+impl<D: Debounce<State = DebounceState>> Debounce for AutoRepeat<D> {
+    type Error = D::Error;
+    type State = Option<RepeatEvent>;
+
+    /// Returns the event from the last update.
+    fn state(&self) -> Self::State {
+        self.event
+    }
+
+    /// Updates the wrapped pin and the repeat timing.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        self.event = match self.pin.update_events()? {
+            Some(Edge::Pressed) => {
+                self.current_interval = self.interval;
+                self.countdown = self.delay;
+                Some(RepeatEvent::Pressed)
+            }
+            None if self.pin.state().is_active() => {
+                self.countdown = self.countdown.saturating_sub(1);
+                if self.countdown == 0 {
+                    self.countdown = self.current_interval;
+                    // Never grows the interval, even if `min_interval` is above it.
+                    if self.acceleration > 0 && self.current_interval > self.min_interval {
+                        self.current_interval = self
+                            .current_interval
+                            .saturating_sub(self.acceleration)
+                            .max(self.min_interval);
+                    }
+                    Some(RepeatEvent::Repeat)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        Ok(self.event)
+    }
+
+    /// Checks if the wrapped pin is settled and not held.
+    fn is_settled(&self) -> bool {
+        self.pin.is_settled() && !self.pin.state().is_active()
+    }
+}
@@ -0,0 +1,24 @@
+//! A sampled level which can be debounced like a pin.
+
+use core::convert::Infallible;
+use embedded_hal::digital::{ErrorType, InputPin};
+
+/// A sampled level, debounced like a pin.
+///
+/// Used where the level is not read from a pin directly, such as a key of a
+/// `KeyMatrix` or an edge passed to an `InterruptDebouncer`.
+pub(crate) struct Sample(pub(crate) bool);
+
+impl ErrorType for Sample {
+    type Error = Infallible;
+}
+
+impl InputPin for Sample {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.0)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.0)
+    }
+}
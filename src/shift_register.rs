@@ -0,0 +1,135 @@
+//! A shift-register (history bitmask) debouncer.
+
+use crate::{Activeness, Debounce, DebounceState};
+use embedded_hal::digital::InputPin;
+
+/// An integer used as the sample history of a `ShiftRegisterDebouncer`.
+///
+/// Implemented for `u8`, `u16` and `u32`, keeping the last 8, 16 or 32 samples.
+pub trait History: Copy + PartialEq {
+    /// A history where every sample was inactive.
+    const INACTIVE: Self;
+
+    /// A history where every sample was active.
+    const ACTIVE: Self;
+
+    /// Shifts a sample into the history, dropping the oldest one.
+    fn shift_in(self, active: bool) -> Self;
+}
+
+macro_rules! impl_history {
+    ($($t:ty),*) => {
+        $(
+            impl History for $t {
+                const INACTIVE: Self = 0;
+                const ACTIVE: Self = <$t>::MAX;
+
+                fn shift_in(self, active: bool) -> Self {
+                    (self << 1) | active as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_history!(u8, u16, u32);
+
+/// An input pin debounced by a shift register.
+///
+/// Implements Jack Ganssle's shift-register debouncer. Every sample is shifted
+/// into an integer of type `H`, and the pin is stable once the whole register is
+/// all ones or all zeros. `H` defaults to `u8`, so the pin becomes active after
+/// 8 consecutive active samples.
+///
+/// Requires `update()` to be called every ~1ms.
+pub struct ShiftRegisterDebouncer<T: InputPin, A, H: History = u8> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The debounced pin state.
+    debounce_state: DebounceState,
+
+    /// The last samples, newest in the least significant bit.
+    history: H,
+}
+
+impl<T: InputPin, A> ShiftRegisterDebouncer<T, A> {
+    /// Initializes a new shift-register debouncer with an 8 sample history.
+    pub fn new(pin: T, activeness: A) -> Self {
+        Self::new_with_history(pin, activeness)
+    }
+}
+
+impl<T: InputPin, A, H: History> ShiftRegisterDebouncer<T, A, H> {
+    /// Initializes a new shift-register debouncer with a history of type `H`.
+    ///
+    /// ```rust,ignore
+    /// let pin = ShiftRegisterDebouncer::<_, _, u16>::new_with_history(pin, ActiveHigh);
+    /// ```
+    pub fn new_with_history(pin: T, activeness: A) -> Self {
+        Self {
+            pin,
+            activeness,
+            debounce_state: DebounceState::NotActive,
+            history: H::INACTIVE,
+        }
+    }
+
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Shifts a sample of whether the pin is active into the history.
+    fn shift(&mut self, active: bool) -> DebounceState {
+        self.history = self.history.shift_in(active);
+
+        let was_active = self.debounce_state.is_active();
+        self.debounce_state = if self.history == H::ACTIVE {
+            DebounceState::Active
+        } else if self.history == H::INACTIVE {
+            if was_active {
+                DebounceState::Reset
+            } else {
+                DebounceState::NotActive
+            }
+        } else if was_active {
+            DebounceState::Releasing
+        } else {
+            DebounceState::Debouncing
+        };
+
+        self.debounce_state
+    }
+}
+
+impl<T: InputPin, A: Activeness, H: History> Debounce for ShiftRegisterDebouncer<T, A, H> {
+    type Error = T::Error;
+    type State = DebounceState;
+
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
+    }
+
+    /// Shifts the current pin state into the history.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        let active = self.activeness.is_active(&mut self.pin)?;
+        Ok(self.shift(active))
+    }
+
+    /// Checks if the whole history is active or inactive.
+    fn is_settled(&self) -> bool {
+        self.history == H::ACTIVE || self.history == H::INACTIVE
+    }
+}
+
+impl_input_pin!(
+    impl<T: InputPin, A: Activeness, H: History> ShiftRegisterDebouncer<T, A, H>,
+    T::Error
+);
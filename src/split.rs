@@ -0,0 +1,269 @@
+//! Sharing a debounced pin between an interrupt handler and the main loop.
+
+use crate::{
+    Activeness, ConstThreshold, Debounce, DebounceState, DebouncedInputPin, Edge, RuntimeThreshold,
+    Threshold,
+};
+use core::convert::Infallible;
+use core::sync::atomic::{AtomicU8, Ordering};
+use embedded_hal::digital::{ErrorType, InputPin};
+
+/// Every `DebounceState`, indexed by its encoding in `SharedPinState`.
+const STATES: [DebounceState; 6] = [
+    DebounceState::Debouncing,
+    DebounceState::Reset,
+    DebounceState::NotActive,
+    DebounceState::Active,
+    DebounceState::Releasing,
+    DebounceState::Initializing,
+];
+
+/// The bit of the encoded state which is set while the debounced pin is high.
+const HIGH: u8 = 0x80;
+
+/// The state of a split `DebouncedInputPin`, shared by its `PinUpdater` and
+/// its `PinReader`s.
+///
+/// Only uses atomic loads and stores, so it also works on targets without
+/// compare-and-swap such as `thumbv6m`. This relies on the `PinUpdater` being
+/// the only writer, so `split()` borrows the state mutably for as long as the
+/// halves live. A `&'static mut` to it can be taken from a singleton.
+///
+/// ```rust,ignore
+/// let button = cortex_m::singleton!(: SharedPinState = SharedPinState::new()).unwrap();
+///
+/// let (updater, reader) = DebouncedInputPin::new(pin, ActiveHigh).split(button);
+/// ```
+pub struct SharedPinState {
+    /// The encoded `DebounceState`, with `HIGH` set while the debounced pin is high.
+    state: AtomicU8,
+
+    /// The number of debounced presses, wrapping around.
+    presses: AtomicU8,
+
+    /// The number of debounced releases, wrapping around.
+    releases: AtomicU8,
+}
+
+impl SharedPinState {
+    /// Initializes a new shared state.
+    pub const fn new() -> Self {
+        Self {
+            // `DebounceState::NotActive`, until the pin is split.
+            state: AtomicU8::new(2),
+            presses: AtomicU8::new(0),
+            releases: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Default for SharedPinState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The half of a split `DebouncedInputPin` which updates it, usually owned by
+/// the interrupt handler.
+pub struct PinUpdater<'a, T: InputPin, A, Th = ConstThreshold<10>> {
+    /// The debounced pin.
+    pin: DebouncedInputPin<T, A, Th>,
+
+    /// The state shared with the readers.
+    shared: &'a SharedPinState,
+}
+
+/// The half of a split `DebouncedInputPin` which reads it without locking,
+/// usually owned by the main loop.
+///
+/// Edges are latched, so presses and releases between two reads are not
+/// missed. Every clone of a reader takes the edges separately.
+#[derive(Clone)]
+pub struct PinReader<'a> {
+    /// The state shared with the updater.
+    shared: &'a SharedPinState,
+
+    /// The number of presses taken so far.
+    presses: u8,
+
+    /// The number of releases taken so far.
+    releases: u8,
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> DebouncedInputPin<T, A, Th> {
+    /// Splits the pin into an updater for the interrupt handler and a reader
+    /// for the main loop, which communicate through `shared`.
+    ///
+    /// `shared` is borrowed mutably, so no other updater can write to it.
+    pub fn split(self, shared: &mut SharedPinState) -> (PinUpdater<'_, T, A, Th>, PinReader<'_>) {
+        let shared = &*shared;
+        let updater = PinUpdater { pin: self, shared };
+        updater.publish();
+
+        let reader = PinReader {
+            shared,
+            presses: shared.presses.load(Ordering::Acquire),
+            releases: shared.releases.load(Ordering::Acquire),
+        };
+        (updater, reader)
+    }
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> PinUpdater<'_, T, A, Th> {
+    /// Returns the debounced pin.
+    pub fn pin(&self) -> &DebouncedInputPin<T, A, Th> {
+        &self.pin
+    }
+
+    /// Changes the number of inactive ticks required before the pin is
+    /// released, see `DebouncedInputPin::set_release_threshold()`.
+    pub fn set_release_threshold(&mut self, release_threshold: u16) {
+        self.pin.set_release_threshold(release_threshold);
+    }
+
+    /// Changes the number of ticks the pin is ignored for after a reset, see
+    /// `DebouncedInputPin::set_blanking()`.
+    pub fn set_blanking(&mut self, blanking: u16) {
+        self.pin.set_blanking(blanking);
+        self.publish();
+    }
+
+    /// Starts over as if the pin was just constructed and publishes the result,
+    /// see `DebouncedInputPin::reset()`.
+    ///
+    /// A release of an active pin is latched on the next `update()`.
+    pub fn reset(&mut self) {
+        self.pin.reset();
+        self.publish();
+    }
+
+    /// Reads the pin, jumps straight to its debounced state and publishes the
+    /// result, see `DebouncedInputPin::sync()`.
+    pub fn sync(&mut self) -> Result<DebounceState, T::Error> {
+        let previous = self.pin.state();
+        let next = self.pin.sync()?;
+        self.latch_edge(Edge::between(previous, next));
+        self.publish();
+        Ok(next)
+    }
+
+    /// Stores the debounce state for the readers.
+    fn publish(&self) {
+        let state = self.pin.debounce_state;
+        let mut encoded = STATES.iter().position(|&s| s == state).unwrap_or(0) as u8;
+        if self.pin.is_active() == self.pin.activeness.is_high_when_active() {
+            encoded |= HIGH;
+        }
+
+        self.shared.state.store(encoded, Ordering::Release);
+    }
+
+    /// Counts an edge for the readers.
+    ///
+    /// The updater is the only writer, so no read-modify-write is needed.
+    fn latch_edge(&self, edge: Option<Edge>) {
+        let counter = match edge {
+            Some(Edge::Pressed) => &self.shared.presses,
+            Some(Edge::Released) => &self.shared.releases,
+            None => return,
+        };
+        let count = counter.load(Ordering::Relaxed);
+        counter.store(count.wrapping_add(1), Ordering::Release);
+    }
+}
+
+impl<T: InputPin, A: Activeness> PinUpdater<'_, T, A, RuntimeThreshold> {
+    /// Changes the number of active ticks required before the pin is
+    /// debounced, see `DebouncedInputPin::set_threshold()`.
+    pub fn set_threshold(&mut self, threshold: u16) {
+        self.pin.set_threshold(threshold);
+    }
+}
+
+impl<T: InputPin, A: Activeness, Th: Threshold> Debounce for PinUpdater<'_, T, A, Th> {
+    type Error = T::Error;
+    type State = DebounceState;
+
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.pin.state()
+    }
+
+    /// Updates the debounce logic and publishes the result to the readers.
+    ///
+    /// Needs to be called every ~1ms.
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        // Latch the edge before publishing the state, so a reader which sees
+        // the new state also sees its edge.
+        let edge = self.pin.update_events()?;
+        self.latch_edge(edge);
+        self.publish();
+
+        Ok(self.pin.state())
+    }
+
+    /// Checks if the debounced pin is settled.
+    fn is_settled(&self) -> bool {
+        self.pin.is_settled()
+    }
+}
+
+impl PinReader<'_> {
+    /// Returns the debounce state from the last update.
+    pub fn state(&self) -> DebounceState {
+        let encoded = self.shared.state.load(Ordering::Acquire) & !HIGH;
+        STATES[encoded as usize]
+    }
+
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.state().is_active()
+    }
+
+    /// Returns the number of debounced presses since the last call.
+    ///
+    /// Wraps around after 255 presses.
+    pub fn take_presses(&mut self) -> u8 {
+        let presses = self.shared.presses.load(Ordering::Acquire);
+        let taken = presses.wrapping_sub(self.presses);
+        self.presses = presses;
+        taken
+    }
+
+    /// Returns the number of debounced releases since the last call.
+    ///
+    /// Wraps around after 255 releases.
+    pub fn take_releases(&mut self) -> u8 {
+        let releases = self.shared.releases.load(Ordering::Acquire);
+        let taken = releases.wrapping_sub(self.releases);
+        self.releases = releases;
+        taken
+    }
+}
+
+impl ErrorType for PinReader<'_> {
+    type Error = Infallible;
+}
+
+impl InputPin for PinReader<'_> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.shared.state.load(Ordering::Acquire) & HIGH != 0)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.shared.state.load(Ordering::Acquire) & HIGH == 0)
+    }
+}
+
+#[cfg(feature = "embedded-hal-02")]
+impl embedded_hal_02::digital::v2::InputPin for PinReader<'_> {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.shared.state.load(Ordering::Acquire) & HIGH != 0)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.shared.state.load(Ordering::Acquire) & HIGH == 0)
+    }
+}
@@ -1,16 +1,42 @@
 use crate::prelude::*;
-use embedded_hal::digital::v2::InputPin;
-use failure::Fail;
+use crate::{ConstThreshold, RuntimeThreshold};
+use embedded_hal::digital::{ErrorType, InputPin};
 use mocks::*;
 
+mod activeness;
+mod array;
+mod click;
+mod edges;
+mod encoder;
+#[cfg(feature = "embedded-hal-02")]
+mod hal02;
+mod integrator;
+mod interrupt;
+mod lockout;
+mod long_press;
+mod matrix;
+mod polarity;
+mod port;
+mod repeat;
+mod shift_register;
+mod split;
+mod timed;
+#[cfg(feature = "async")]
+mod wait;
+
 /// Mock implementations.
 mod mocks {
     use super::*;
 
-    #[derive(Debug, Fail)]
-    #[fail(display = "An error occurred")]
+    #[derive(Debug)]
     pub struct MockInputPinError;
 
+    impl embedded_hal::digital::Error for MockInputPinError {
+        fn kind(&self) -> embedded_hal::digital::ErrorKind {
+            embedded_hal::digital::ErrorKind::Other
+        }
+    }
+
     /// A mock implementation of `InputPin`.
     #[derive(Default)]
     pub struct MockInputPin {
@@ -18,14 +44,16 @@ mod mocks {
         pub state: bool,
     }
 
-    impl InputPin for MockInputPin {
+    impl ErrorType for MockInputPin {
         type Error = MockInputPinError;
+    }
 
-        fn is_high(&self) -> Result<bool, MockInputPinError> {
+    impl InputPin for MockInputPin {
+        fn is_high(&mut self) -> Result<bool, MockInputPinError> {
             Ok(self.state)
         }
 
-        fn is_low(&self) -> Result<bool, MockInputPinError> {
+        fn is_low(&mut self) -> Result<bool, MockInputPinError> {
             Ok(!self.state)
         }
     }
@@ -39,7 +67,6 @@ mod input_pin {
     mod active_high {
         use super::*;
         use crate::ActiveHigh; // Not importing `ActiveHigh` further up the chain to prevent mistakes.
-        use embedded_hal::digital::v2::InputPin;
 
         /// Creates a `DebouncedInputPin<MockInputPin, ActiveHigh>`.
         pub fn create_pin() -> DebouncedInputPin<MockInputPin, ActiveHigh> {
@@ -87,17 +114,17 @@ mod input_pin {
         {
             let mut pin = create_pin();
             pin.debounce_state = DebounceState::Active;
-            assert_eq!(pin.is_high()?, true);
-            assert_eq!(pin.is_low()?, false);
+            assert!(pin.is_high()?);
+            assert!(!pin.is_low()?);
             pin.debounce_state = DebounceState::NotActive;
-            assert_eq!(pin.is_high()?, false);
-            assert_eq!(pin.is_low()?, true);
+            assert!(!pin.is_high()?);
+            assert!(pin.is_low()?);
             pin.debounce_state = DebounceState::Debouncing;
-            assert_eq!(pin.is_high()?, false);
-            assert_eq!(pin.is_low()?, true);
+            assert!(!pin.is_high()?);
+            assert!(pin.is_low()?);
             pin.debounce_state = DebounceState::Reset;
-            assert_eq!(pin.is_high()?, false);
-            assert_eq!(pin.is_low()?, true);
+            assert!(!pin.is_high()?);
+            assert!(pin.is_low()?);
             Ok(())
         }
 
@@ -121,13 +148,13 @@ mod input_pin {
         fn it_returns_true_when_pin_is_active_and_vice_versa() -> Result<(), MockInputPinError> {
             let mut pin = create_pin();
             pin.debounce_state = DebounceState::Active;
-            assert_eq!(pin.is_active(), true);
+            assert!(pin.is_active());
             pin.debounce_state = DebounceState::NotActive;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             pin.debounce_state = DebounceState::Debouncing;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             pin.debounce_state = DebounceState::Reset;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             Ok(())
         }
 
@@ -199,17 +226,17 @@ mod input_pin {
         {
             let mut pin = create_pin();
             pin.debounce_state = DebounceState::Active;
-            assert_eq!(pin.is_high()?, false);
-            assert_eq!(pin.is_low()?, true);
+            assert!(!pin.is_high()?);
+            assert!(pin.is_low()?);
             pin.debounce_state = DebounceState::NotActive;
-            assert_eq!(pin.is_high()?, true);
-            assert_eq!(pin.is_low()?, false);
+            assert!(pin.is_high()?);
+            assert!(!pin.is_low()?);
             pin.debounce_state = DebounceState::Debouncing;
-            assert_eq!(pin.is_high()?, true);
-            assert_eq!(pin.is_low()?, false);
+            assert!(pin.is_high()?);
+            assert!(!pin.is_low()?);
             pin.debounce_state = DebounceState::Reset;
-            assert_eq!(pin.is_high()?, true);
-            assert_eq!(pin.is_low()?, false);
+            assert!(pin.is_high()?);
+            assert!(!pin.is_low()?);
             Ok(())
         }
 
@@ -233,13 +260,13 @@ mod input_pin {
         fn it_returns_true_when_pin_is_active_and_vice_versa() -> Result<(), MockInputPinError> {
             let mut pin = create_pin();
             pin.debounce_state = DebounceState::Active;
-            assert_eq!(pin.is_active(), true);
+            assert!(pin.is_active());
             pin.debounce_state = DebounceState::NotActive;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             pin.debounce_state = DebounceState::Debouncing;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             pin.debounce_state = DebounceState::Reset;
-            assert_eq!(pin.is_active(), false);
+            assert!(!pin.is_active());
             Ok(())
         }
 
@@ -259,4 +286,310 @@ mod input_pin {
             Ok(())
         }
     }
+
+    /// Tests for `DebouncedInputPin<T, A, Th>` with a non-default threshold.
+    mod threshold {
+        use super::*;
+        use crate::ActiveHigh;
+
+        #[test]
+        fn it_goes_active_after_a_custom_threshold() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::<_, _, ConstThreshold<3>>::new_with_threshold(
+                MockInputPin::default(),
+                ActiveHigh,
+            );
+            pin.pin.state = true;
+            for _ in 0..3 {
+                assert_eq!(pin.update()?, DebounceState::Debouncing);
+            }
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_supports_thresholds_above_127() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::<_, _, ConstThreshold<500>>::new_with_threshold(
+                MockInputPin::default(),
+                ActiveHigh,
+            );
+            pin.pin.state = true;
+            for _ in 0..500 {
+                assert_eq!(pin.update()?, DebounceState::Debouncing);
+            }
+            assert_eq!(pin.counter, 500);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_builds_a_pin_with_a_runtime_threshold() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin::default(), ActiveHigh)
+                .threshold(2)
+                .build();
+            assert_eq!(pin.threshold(), 2);
+            pin.pin.state = true;
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_clamps_the_counter_when_lowering_the_threshold() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin::default(), ActiveHigh).build();
+            pin.pin.state = true;
+            for _ in 0..8 {
+                pin.update()?;
+            }
+            pin.set_threshold(5);
+            assert_eq!(pin.counter, 5);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_stays_active_when_raising_the_threshold() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin::default(), ActiveHigh).build();
+            pin.pin.state = true;
+            pin.counter = 10;
+            assert_eq!(pin.update()?, DebounceState::Active);
+            pin.set_threshold(20);
+            assert_eq!(pin.counter, 20);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+    }
+
+    /// Tests for release debouncing.
+    mod release {
+        use super::*;
+        use crate::ActiveHigh;
+
+        /// Creates an active `DebouncedInputPin` with a release threshold of 3.
+        fn create_active_pin() -> DebouncedInputPin<MockInputPin, ActiveHigh, RuntimeThreshold> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin { state: true }, ActiveHigh)
+                .release_threshold(3)
+                .build();
+            pin.counter = 10;
+            pin.debounce_state = DebounceState::Active;
+            pin
+        }
+
+        #[test]
+        fn it_debounces_the_release() -> Result<(), MockInputPinError> {
+            let mut pin = create_active_pin();
+            pin.pin.state = false;
+            for _ in 0..3 {
+                assert_eq!(pin.update()?, DebounceState::Releasing);
+                assert!(pin.is_high()?);
+            }
+            assert_eq!(pin.update()?, DebounceState::Reset);
+            assert!(pin.is_low()?);
+            assert_eq!(pin.counter, 0);
+            Ok(())
+        }
+
+        #[test]
+        fn it_returns_to_active_on_release_bounce() -> Result<(), MockInputPinError> {
+            let mut pin = create_active_pin();
+            pin.pin.state = false;
+            assert_eq!(pin.update()?, DebounceState::Releasing);
+            assert_eq!(pin.update()?, DebounceState::Releasing);
+            pin.pin.state = true;
+            assert_eq!(pin.update()?, DebounceState::Active);
+            pin.pin.state = false;
+            for _ in 0..3 {
+                assert_eq!(pin.update()?, DebounceState::Releasing);
+            }
+            assert_eq!(pin.update()?, DebounceState::Reset);
+            Ok(())
+        }
+
+        #[test]
+        fn it_releases_immediately_by_default() -> Result<(), MockInputPinError> {
+            let mut pin = create_active_pin();
+            pin.set_release_threshold(0);
+            pin.pin.state = false;
+            assert_eq!(pin.update()?, DebounceState::Reset);
+            Ok(())
+        }
+    }
+
+    /// Tests for starting in the pin's current state.
+    mod synced {
+        use super::*;
+        use crate::{ActiveHigh, ActiveLow};
+
+        #[test]
+        fn it_starts_active_when_the_pin_is_active() -> Result<(), MockInputPinError> {
+            let mut pin =
+                DebouncedInputPin::<_, _>::new_synced(MockInputPin { state: true }, ActiveHigh)?;
+            assert_eq!(pin.state(), DebounceState::Active);
+            assert_eq!(pin.counter, 10);
+            assert!(pin.is_high()?);
+            assert_eq!(pin.update_events()?, None);
+            Ok(())
+        }
+
+        #[test]
+        fn it_starts_not_active_when_the_pin_is_not_active() -> Result<(), MockInputPinError> {
+            let mut pin =
+                DebouncedInputPin::<_, _>::new_synced(MockInputPin { state: true }, ActiveLow)?;
+            assert_eq!(pin.state(), DebounceState::NotActive);
+            assert_eq!(pin.counter, 0);
+            assert!(pin.is_high()?);
+            assert_eq!(pin.update_events()?, None);
+            Ok(())
+        }
+
+        #[test]
+        fn it_starts_synced_with_a_const_threshold() -> Result<(), MockInputPinError> {
+            let pin = DebouncedInputPin::<_, _, ConstThreshold<50>>::new_synced(
+                MockInputPin { state: true },
+                ActiveHigh,
+            )?;
+            assert_eq!(pin.state(), DebounceState::Active);
+            assert_eq!(pin.counter, 50);
+            Ok(())
+        }
+
+        #[test]
+        fn it_syncs_to_a_runtime_threshold() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin { state: true }, ActiveHigh)
+                .threshold(3)
+                .build();
+            assert_eq!(pin.sync()?, DebounceState::Active);
+            assert_eq!(pin.counter, 3);
+            pin.pin.state = false;
+            assert_eq!(pin.update_events()?, Some(Edge::Released));
+            Ok(())
+        }
+    }
+
+    /// Tests for `is_settled()`.
+    mod settled {
+        use super::*;
+        use crate::ActiveHigh;
+
+        #[test]
+        fn it_is_settled_while_steady() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::<_, _, ConstThreshold<2>>::new_with_threshold(
+                MockInputPin::default(),
+                ActiveHigh,
+            );
+            assert!(pin.is_settled());
+            pin.pin.state = true;
+            pin.update()?;
+            assert!(!pin.is_settled());
+            pin.update()?;
+            pin.update()?;
+            assert!(pin.is_settled());
+            Ok(())
+        }
+
+        #[test]
+        fn it_is_not_settled_while_releasing() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::builder(MockInputPin { state: true }, ActiveHigh)
+                .release_threshold(1)
+                .build();
+            pin.sync()?;
+            pin.pin.state = false;
+            assert_eq!(pin.update()?, DebounceState::Releasing);
+            assert!(!pin.is_settled());
+            assert_eq!(pin.update()?, DebounceState::Reset);
+            assert!(pin.is_settled());
+            Ok(())
+        }
+
+        #[test]
+        fn it_is_not_settled_while_blanking() {
+            let pin = DebouncedInputPin::builder(MockInputPin::default(), ActiveHigh)
+                .blanking(1)
+                .build();
+            assert!(!pin.is_settled());
+        }
+    }
+
+    /// Tests for the startup blanking period.
+    mod blanking {
+        use super::*;
+        use crate::ActiveHigh;
+
+        /// Creates an active-high `DebouncedInputPin` with a threshold of 1 and 3 ticks of blanking.
+        fn create_pin() -> DebouncedInputPin<MockInputPin, ActiveHigh, RuntimeThreshold> {
+            DebouncedInputPin::builder(MockInputPin { state: true }, ActiveHigh)
+                .threshold(1)
+                .blanking(3)
+                .build()
+        }
+
+        #[test]
+        fn it_ignores_the_pin_while_blanking() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            assert_eq!(pin.state(), DebounceState::Initializing);
+            for _ in 0..3 {
+                assert_eq!(pin.update()?, DebounceState::Initializing);
+                assert!(pin.is_low()?);
+            }
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_blanks_again_after_a_reset() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.sync()?;
+            assert_eq!(pin.update()?, DebounceState::Active);
+            pin.reset();
+            assert!(!pin.is_active());
+            for _ in 0..3 {
+                assert_eq!(pin.update()?, DebounceState::Initializing);
+            }
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+            Ok(())
+        }
+
+        #[test]
+        fn it_blanks_from_construction_when_set_before_the_first_update(
+        ) -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+                MockInputPin { state: true },
+                ActiveHigh,
+            );
+            pin.set_blanking(2);
+            assert_eq!(pin.state(), DebounceState::Initializing);
+            for _ in 0..2 {
+                assert_eq!(pin.update()?, DebounceState::Initializing);
+            }
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+
+            pin.set_blanking(5);
+            assert_eq!(pin.update()?, DebounceState::Active);
+            Ok(())
+        }
+
+        #[test]
+        fn it_reports_a_release_after_resetting_an_active_pin() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+                MockInputPin { state: true },
+                ActiveHigh,
+            );
+            pin.update()?;
+            assert_eq!(pin.update_events()?, Some(Edge::Pressed));
+            pin.reset();
+            assert!(!pin.is_settled());
+            assert_eq!(pin.update_events()?, Some(Edge::Released));
+            assert_eq!(pin.update_events()?, Some(Edge::Pressed));
+            Ok(())
+        }
+
+        #[test]
+        fn it_does_not_blank_by_default() -> Result<(), MockInputPinError> {
+            let mut pin = DebouncedInputPin::new(MockInputPin { state: true }, ActiveHigh);
+            pin.reset();
+            assert_eq!(pin.update()?, DebounceState::Debouncing);
+            Ok(())
+        }
+    }
 }
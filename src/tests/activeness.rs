@@ -0,0 +1,50 @@
+//! Tests for custom `Activeness` implementations.
+
+use super::*;
+use crate::{Activeness, IntegratingDebouncer};
+
+/// Active while the pin differs from its level at boot.
+struct ChangedFromBoot {
+    boot_level_high: bool,
+}
+
+impl Activeness for ChangedFromBoot {
+    fn is_active<P: InputPin>(&self, pin: &mut P) -> Result<bool, P::Error> {
+        Ok(pin.is_high()? != self.boot_level_high)
+    }
+
+    fn is_high_when_active(&self) -> bool {
+        !self.boot_level_high
+    }
+}
+
+#[test]
+fn it_debounces_with_a_custom_activeness() -> Result<(), MockInputPinError> {
+    let activeness = ChangedFromBoot {
+        boot_level_high: true,
+    };
+    let mut pin = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin { state: true },
+        activeness,
+    );
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = false;
+    assert_eq!(pin.update()?, DebounceState::Debouncing);
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_works_with_other_debouncers() -> Result<(), MockInputPinError> {
+    let activeness = ChangedFromBoot {
+        boot_level_high: false,
+    };
+    let mut pin = IntegratingDebouncer::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin { state: true },
+        activeness,
+    );
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_high()?);
+    Ok(())
+}
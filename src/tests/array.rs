@@ -0,0 +1,118 @@
+//! Tests for `DebouncedPinArray<P, N>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, DebouncedPinArray, IntegratingDebouncer, PinError};
+
+/// A mock pin which always fails.
+struct FailingPin;
+
+impl Debounce for FailingPin {
+    type Error = MockInputPinError;
+    type State = DebounceState;
+
+    fn state(&self) -> Self::State {
+        DebounceState::NotActive
+    }
+
+    fn update(&mut self) -> Result<Self::State, Self::Error> {
+        Err(MockInputPinError)
+    }
+
+    fn is_settled(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn it_updates_all_pins_and_reports_their_edges() -> Result<(), PinError<MockInputPinError>> {
+    let pins = [
+        DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+            MockInputPin { state: true },
+            ActiveHigh,
+        ),
+        DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+            MockInputPin { state: false },
+            ActiveHigh,
+        ),
+    ];
+    let mut array = DebouncedPinArray::new(pins);
+    assert_eq!(array.update_all()?, [None, None]);
+    assert_eq!(array.update_all()?, [Some(Edge::Pressed), None]);
+    assert_eq!(
+        array.states(),
+        [DebounceState::Active, DebounceState::NotActive]
+    );
+    assert!(array.is_active(0));
+    array.pins[0].pin.state = false;
+    assert_eq!(array.update_all()?, [Some(Edge::Released), None]);
+    Ok(())
+}
+
+#[test]
+fn it_groups_type_erased_pins() -> Result<(), PinError<MockInputPinError>> {
+    let mut high = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin { state: true },
+        ActiveHigh,
+    );
+    let mut low = IntegratingDebouncer::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin { state: false },
+        ActiveLow,
+    );
+    let mut array = DebouncedPinArray::new([
+        &mut high as &mut dyn Debounce<Error = _, State = _>,
+        &mut low,
+    ]);
+    assert_eq!(array.update_all()?, [None, Some(Edge::Pressed)]);
+    assert_eq!(array.update_all()?, [Some(Edge::Pressed), None]);
+    Ok(())
+}
+
+#[test]
+fn it_reports_the_index_of_a_failing_pin() {
+    let mut pin = DebouncedInputPin::new(MockInputPin::default(), ActiveHigh);
+    let mut failing = FailingPin;
+    let mut array = DebouncedPinArray::new([
+        &mut pin as &mut dyn Debounce<Error = _, State = _>,
+        &mut failing,
+    ]);
+    let error = array.update_all().unwrap_err();
+    assert_eq!(error.index, 1);
+}
+
+#[test]
+fn it_keeps_the_edges_of_the_other_pins_on_failure() {
+    let mut failing = FailingPin;
+    let mut pin = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin { state: true },
+        ActiveHigh,
+    );
+    let mut array = DebouncedPinArray::new([
+        &mut failing as &mut dyn Debounce<Error = _, State = _>,
+        &mut pin,
+    ]);
+    assert_eq!(array.update_all().unwrap_err().index, 0);
+    assert_eq!(array.update_all().unwrap_err().index, 0);
+    assert_eq!(array.edges(), [None, Some(Edge::Pressed)]);
+    assert!(array.is_active(1));
+}
+
+#[test]
+fn it_is_settled_once_every_pin_is() -> Result<(), PinError<MockInputPinError>> {
+    let pins = [
+        DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+            MockInputPin { state: true },
+            ActiveHigh,
+        ),
+        DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+            MockInputPin { state: false },
+            ActiveHigh,
+        ),
+    ];
+    let mut array = DebouncedPinArray::new(pins);
+    assert!(array.is_settled());
+    array.update_all()?;
+    assert!(!array.is_settled());
+    array.update_all()?;
+    assert!(array.is_settled());
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Tests for `ClickRecognizer<D>`.
+
+use super::*;
+use crate::{ActiveHigh, Click, ClickRecognizer};
+
+type Recognizer = ClickRecognizer<DebouncedInputPin<MockInputPin, ActiveHigh, ConstThreshold<1>>>;
+
+/// Creates a `ClickRecognizer` with a 3 tick gap around a pin with a 1 tick threshold.
+fn create_pin() -> Recognizer {
+    let pin = DebouncedInputPin::new_with_threshold(MockInputPin::default(), ActiveHigh);
+    ClickRecognizer::new(pin, 3)
+}
+
+/// Presses and releases the pin, returning the event from the release.
+fn click(pin: &mut Recognizer) -> Result<Option<Click>, MockInputPinError> {
+    pin.pin.pin.state = true;
+    assert_eq!(pin.update()?, None);
+    assert_eq!(pin.update()?, None);
+    pin.pin.pin.state = false;
+    pin.update()
+}
+
+#[test]
+fn it_reports_a_single_click_once_the_gap_expires() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert_eq!(click(&mut pin)?, None);
+    assert_eq!(pin.update()?, None);
+    assert_eq!(pin.update()?, None);
+    assert_eq!(pin.update()?, Some(Click(1)));
+    assert_eq!(pin.update()?, None);
+    Ok(())
+}
+
+#[test]
+fn it_counts_clicks_within_the_gap() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert_eq!(click(&mut pin)?, None);
+    assert_eq!(pin.update()?, None);
+    assert_eq!(click(&mut pin)?, None);
+    for _ in 0..2 {
+        assert_eq!(pin.update()?, None);
+    }
+    assert_eq!(pin.update()?, Some(Click(2)));
+    Ok(())
+}
+
+#[test]
+fn it_reports_right_away_at_the_maximum() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.set_max_clicks(1);
+    assert_eq!(click(&mut pin)?, Some(Click(1)));
+    assert_eq!(pin.update()?, None);
+    pin.set_max_clicks(2);
+    assert_eq!(click(&mut pin)?, None);
+    assert_eq!(click(&mut pin)?, Some(Click(2)));
+    Ok(())
+}
+
+#[test]
+fn it_is_not_settled_during_the_gap() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert!(pin.is_settled());
+    click(&mut pin)?;
+    assert!(!pin.is_settled());
+    for _ in 0..3 {
+        pin.update()?;
+    }
+    assert!(pin.is_settled());
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! Tests for `Edge` and `update_events()`.
+
+use super::*;
+use crate::{ActiveHigh, IntegratingDebouncer, TimedDebouncer};
+
+#[test]
+fn it_fires_each_edge_exactly_once() -> Result<(), MockInputPinError> {
+    let mut pin = DebouncedInputPin::<_, _, ConstThreshold<2>>::new_with_threshold(
+        MockInputPin::default(),
+        ActiveHigh,
+    );
+    assert_eq!(pin.update_events()?, None);
+    pin.pin.state = true;
+    assert_eq!(pin.update_events()?, None);
+    assert_eq!(pin.update_events()?, None);
+    assert_eq!(pin.update_events()?, Some(Edge::Pressed));
+    assert_eq!(pin.update_events()?, None);
+    assert_eq!(pin.update_events()?, None);
+    pin.pin.state = false;
+    assert_eq!(pin.update_events()?, Some(Edge::Released));
+    assert_eq!(pin.update_events()?, None);
+    Ok(())
+}
+
+#[test]
+fn it_does_not_fire_while_releasing() -> Result<(), MockInputPinError> {
+    let mut pin = IntegratingDebouncer::<_, _, ConstThreshold<2>>::new_with_threshold(
+        MockInputPin { state: true },
+        ActiveHigh,
+    );
+    assert_eq!(pin.update_events()?, None);
+    assert_eq!(pin.update_events()?, Some(Edge::Pressed));
+    pin.pin.state = false;
+    assert_eq!(pin.update_events()?, None);
+    assert_eq!(pin.update_events()?, Some(Edge::Released));
+    Ok(())
+}
+
+#[test]
+fn it_fires_edges_from_timestamped_updates() -> Result<(), MockInputPinError> {
+    let mut pin: TimedDebouncer<_, _, u32> =
+        TimedDebouncer::new(MockInputPin { state: true }, ActiveHigh, 10);
+    assert_eq!(pin.update_events_at(0)?, None);
+    assert_eq!(pin.update_events_at(10)?, Some(Edge::Pressed));
+    assert_eq!(pin.update_events_at(11)?, None);
+    pin.pin.state = false;
+    assert_eq!(pin.update_events_at(12)?, Some(Edge::Released));
+    Ok(())
+}
+
+#[test]
+fn it_detects_edges_between_states() {
+    use DebounceState::*;
+    assert_eq!(Edge::between(Debouncing, Active), Some(Edge::Pressed));
+    assert_eq!(Edge::between(Active, Releasing), None);
+    assert_eq!(Edge::between(Releasing, Reset), Some(Edge::Released));
+    assert_eq!(Edge::between(NotActive, Debouncing), None);
+}
@@ -0,0 +1,150 @@
+//! Tests for `QuadratureEncoder<A, B, S>`.
+
+use super::*;
+use crate::{ActiveHigh, EncoderState, QuadratureEncoder, StepsPerDetent};
+
+/// Creates a `QuadratureEncoder` resting with both channels low.
+fn create_encoder(steps: StepsPerDetent) -> QuadratureEncoder<MockInputPin, MockInputPin> {
+    QuadratureEncoder::new(MockInputPin::default(), MockInputPin::default(), steps)
+}
+
+/// Sets both channels of the encoder and updates it.
+fn turn<S>(
+    encoder: &mut QuadratureEncoder<MockInputPin, MockInputPin, S>,
+    a: bool,
+    b: bool,
+) -> Result<i8, MockInputPinError>
+where
+    QuadratureEncoder<MockInputPin, MockInputPin, S>: Debounce<Error = MockInputPinError>,
+{
+    encoder.a.state = a;
+    encoder.b.state = b;
+    encoder.update()?;
+    Ok(encoder.detents())
+}
+
+/// One clockwise quadrature cycle, starting from both channels low.
+const CLOCKWISE: [(bool, bool); 4] = [(true, false), (true, true), (false, true), (false, false)];
+
+#[test]
+fn it_counts_one_detent_per_cycle() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::Four);
+    assert_eq!(encoder.update()?, 0);
+    for &(a, b) in &CLOCKWISE[..3] {
+        assert_eq!(turn(&mut encoder, a, b)?, 0);
+    }
+    assert_eq!(turn(&mut encoder, false, false)?, 1);
+    for &(a, b) in CLOCKWISE.iter().rev().skip(1) {
+        turn(&mut encoder, a, b)?;
+    }
+    assert_eq!(turn(&mut encoder, false, false)?, -1);
+    Ok(())
+}
+
+#[test]
+fn it_counts_one_and_two_steps_per_detent() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::One);
+    encoder.update()?;
+    for &(a, b) in &CLOCKWISE {
+        assert_eq!(turn(&mut encoder, a, b)?, 1);
+    }
+
+    let mut encoder = create_encoder(StepsPerDetent::Two);
+    encoder.update()?;
+    let detents: [i8; 4] = [
+        turn(&mut encoder, true, false)?,
+        turn(&mut encoder, true, true)?,
+        turn(&mut encoder, false, true)?,
+        turn(&mut encoder, false, false)?,
+    ];
+    assert_eq!(detents, [0, 1, 0, 1]);
+    Ok(())
+}
+
+#[test]
+fn it_cancels_out_bounces() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::Four);
+    encoder.update()?;
+    for _ in 0..3 {
+        assert_eq!(turn(&mut encoder, true, false)?, 0);
+        assert_eq!(turn(&mut encoder, false, false)?, 0);
+    }
+    for &(a, b) in &CLOCKWISE[..3] {
+        assert_eq!(turn(&mut encoder, a, b)?, 0);
+    }
+    assert_eq!(turn(&mut encoder, false, false)?, 1);
+    Ok(())
+}
+
+#[test]
+fn it_rejects_invalid_transitions() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::One);
+    encoder.update()?;
+    assert_eq!(turn(&mut encoder, true, true)?, 0);
+    assert_eq!(turn(&mut encoder, false, false)?, 0);
+    Ok(())
+}
+
+#[test]
+fn it_realigns_after_a_skipped_transition() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::Four);
+    encoder.update()?;
+    assert_eq!(turn(&mut encoder, true, false)?, 0);
+    assert_eq!(turn(&mut encoder, false, true)?, 0);
+    assert_eq!(turn(&mut encoder, false, false)?, 0);
+    assert!(encoder.is_settled());
+
+    for &(a, b) in &CLOCKWISE[..3] {
+        assert_eq!(turn(&mut encoder, a, b)?, 0);
+        assert!(!encoder.is_settled());
+    }
+    assert_eq!(turn(&mut encoder, false, false)?, 1);
+    assert!(encoder.is_settled());
+    Ok(())
+}
+
+#[test]
+fn it_rests_where_the_encoder_starts() -> Result<(), MockInputPinError> {
+    let mut encoder = create_encoder(StepsPerDetent::Four);
+    assert_eq!(turn(&mut encoder, true, true)?, 0);
+    assert!(encoder.is_settled());
+
+    let from_high = [(false, true), (false, false), (true, false), (true, true)];
+    for _ in 0..3 {
+        for &(a, b) in &from_high[..3] {
+            assert_eq!(turn(&mut encoder, a, b)?, 0);
+            assert!(!encoder.is_settled());
+        }
+        assert_eq!(turn(&mut encoder, true, true)?, 1);
+        assert!(encoder.is_settled());
+    }
+    Ok(())
+}
+
+#[test]
+fn it_updates_the_push_button() -> Result<(), MockInputPinError> {
+    let button = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(
+        MockInputPin::default(),
+        ActiveHigh,
+    );
+    let mut encoder = create_encoder(StepsPerDetent::One).with_button(button);
+    encoder.button.pin.state = true;
+    encoder.update()?;
+    assert_eq!(
+        encoder.update()?,
+        EncoderState {
+            detents: 0,
+            button: Some(Edge::Pressed),
+        }
+    );
+    encoder.button.pin.state = false;
+    turn(&mut encoder, true, false)?;
+    assert_eq!(
+        encoder.state(),
+        EncoderState {
+            detents: 1,
+            button: Some(Edge::Released),
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Tests for `embedded-hal` 0.2 support.
+
+use super::*;
+use crate::hal02::{Compat, CompatDelay, CompatError, CompatOutput};
+use crate::{ActiveHigh, KeyMatrix, MatrixError};
+use embedded_hal_02::blocking::delay::DelayUs;
+use embedded_hal_02::digital::v2;
+
+/// A mock implementation of the `embedded-hal` 0.2 `InputPin`.
+#[derive(Default)]
+struct MockInputPin02 {
+    state: bool,
+}
+
+impl v2::InputPin for MockInputPin02 {
+    type Error = MockInputPinError;
+
+    fn is_high(&self) -> Result<bool, MockInputPinError> {
+        Ok(self.state)
+    }
+
+    fn is_low(&self) -> Result<bool, MockInputPinError> {
+        Ok(!self.state)
+    }
+}
+
+/// A mock implementation of the `embedded-hal` 0.2 `OutputPin`.
+#[derive(Default)]
+struct MockOutputPin02 {
+    state: bool,
+}
+
+impl v2::OutputPin for MockOutputPin02 {
+    type Error = MockInputPinError;
+
+    fn set_low(&mut self) -> Result<(), MockInputPinError> {
+        self.state = false;
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), MockInputPinError> {
+        self.state = true;
+        Ok(())
+    }
+}
+
+/// A mock implementation of the `embedded-hal` 0.2 `DelayUs` which records the
+/// requested delays.
+#[derive(Default)]
+struct MockDelay02 {
+    total_us: u32,
+}
+
+impl DelayUs<u32> for MockDelay02 {
+    fn delay_us(&mut self, us: u32) {
+        self.total_us += us;
+    }
+}
+
+#[test]
+fn it_debounces_a_0_2_pin() -> Result<(), CompatError<MockInputPinError>> {
+    let pin = Compat(MockInputPin02 { state: true });
+    let mut pin = DebouncedInputPin::<_, _, ConstThreshold<1>>::new_with_threshold(pin, ActiveHigh);
+    assert_eq!(pin.update()?, DebounceState::Debouncing);
+    assert_eq!(pin.update()?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_implements_the_0_2_input_pin() -> Result<(), CompatError<MockInputPinError>> {
+    let mut pin =
+        DebouncedInputPin::<_, _>::new_synced(Compat(MockInputPin02 { state: true }), ActiveHigh)?;
+    assert!(v2::InputPin::is_high(&pin)?);
+    pin.pin.0.state = false;
+    pin.update()?;
+    assert!(v2::InputPin::is_low(&pin)?);
+    Ok(())
+}
+
+#[test]
+fn it_scans_a_matrix_with_0_2_parts(
+) -> Result<(), MatrixError<CompatError<MockInputPinError>, CompatError<MockInputPinError>>> {
+    let rows = [CompatOutput(MockOutputPin02::default())];
+    let cols = [Compat(MockInputPin02 { state: false })];
+    let mut matrix = KeyMatrix::new(rows, cols);
+    matrix.set_threshold(1);
+    matrix.set_settle_us(5);
+
+    let mut delay = CompatDelay(MockDelay02::default());
+    matrix.scan(&mut delay, |_| {})?;
+    matrix.scan(&mut delay, |_| {})?;
+    assert!(matrix.is_down(0, 0));
+    assert!(matrix.rows[0].0.state);
+    assert_eq!(delay.0.total_us, 10);
+    Ok(())
+}
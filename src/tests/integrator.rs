@@ -0,0 +1,81 @@
+//! Tests for `IntegratingDebouncer<T, A, Th>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, IntegratingDebouncer};
+
+/// Creates an `IntegratingDebouncer<MockInputPin, ActiveHigh, ConstThreshold<4>>`.
+fn create_pin() -> IntegratingDebouncer<MockInputPin, ActiveHigh, ConstThreshold<4>> {
+    IntegratingDebouncer::new_with_threshold(MockInputPin::default(), ActiveHigh)
+}
+
+#[test]
+fn it_goes_active_when_the_integrator_saturates() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = true;
+    for _ in 0..3 {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_tolerates_noise_while_pressing() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    for &sample in &[true, true, false, true, true, false, true] {
+        pin.pin.state = sample;
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    pin.pin.state = true;
+    assert_eq!(pin.update()?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_releases_when_the_integrator_drains() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.pin.state = true;
+    for _ in 0..4 {
+        pin.update()?;
+    }
+    pin.pin.state = false;
+    for _ in 0..3 {
+        assert_eq!(pin.update()?, DebounceState::Releasing);
+        assert!(pin.is_high()?);
+    }
+    assert_eq!(pin.update()?, DebounceState::Reset);
+    assert!(pin.is_low()?);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    Ok(())
+}
+
+#[test]
+fn it_debounces_active_low_pins() -> Result<(), MockInputPinError> {
+    let mut pin = IntegratingDebouncer::new(MockInputPin { state: true }, ActiveLow);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = false;
+    for _ in 0..9 {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_treats_a_threshold_of_0_like_1() -> Result<(), MockInputPinError> {
+    let mut pin = IntegratingDebouncer::<_, _, ConstThreshold<0>>::new_with_threshold(
+        MockInputPin::default(),
+        ActiveHigh,
+    );
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = true;
+    assert_eq!(pin.update()?, DebounceState::Active);
+    pin.pin.state = false;
+    assert_eq!(pin.update()?, DebounceState::Reset);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Tests for `InterruptDebouncer<A, I>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, InterruptDebouncer};
+use core::convert::Infallible;
+
+/// Creates an `InterruptDebouncer<ActiveHigh, u32>` with a 20 tick window.
+fn create_pin() -> InterruptDebouncer<ActiveHigh, u32> {
+    InterruptDebouncer::new(ActiveHigh, 20)
+}
+
+#[test]
+fn it_needs_no_timer_while_steady() {
+    let pin = create_pin();
+    assert_eq!(pin.state(), DebounceState::NotActive);
+    assert_eq!(pin.next_deadline(), None);
+}
+
+#[test]
+fn it_goes_active_at_the_deadline() -> Result<(), Infallible> {
+    let mut pin = create_pin();
+    assert_eq!(pin.on_edge(100, true), DebounceState::Debouncing);
+    assert_eq!(pin.next_deadline(), Some(120));
+    assert_eq!(pin.update_at(119)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_events_at(120)?, Some(Edge::Pressed));
+    assert_eq!(pin.next_deadline(), None);
+    assert!(pin.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_moves_the_deadline_on_every_bounce() -> Result<(), Infallible> {
+    let mut pin = create_pin();
+    pin.on_edge(100, true);
+    assert_eq!(pin.on_edge(102, false), DebounceState::NotActive);
+    assert_eq!(pin.next_deadline(), None);
+    assert_eq!(pin.on_edge(105, true), DebounceState::Debouncing);
+    assert_eq!(pin.next_deadline(), Some(125));
+    assert_eq!(pin.update_at(120)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(125)?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_releases_on_the_first_edge_by_default() -> Result<(), Infallible> {
+    let mut pin = create_pin();
+    pin.on_edge(0, true);
+    pin.update_at(20)?;
+    assert_eq!(pin.on_edge(50, false), DebounceState::Reset);
+    assert!(pin.is_low()?);
+    assert_eq!(pin.next_deadline(), None);
+    assert_eq!(pin.update_at(51)?, DebounceState::NotActive);
+    Ok(())
+}
+
+#[test]
+fn it_debounces_the_release_over_the_release_window() -> Result<(), Infallible> {
+    let mut pin = create_pin();
+    pin.set_release_window(5);
+    pin.on_edge(0, true);
+    pin.update_at(20)?;
+    assert_eq!(pin.on_edge(50, false), DebounceState::Releasing);
+    assert!(pin.is_high()?);
+    assert_eq!(pin.on_edge(51, true), DebounceState::Active);
+    assert_eq!(pin.on_edge(52, false), DebounceState::Releasing);
+    assert_eq!(pin.next_deadline(), Some(57));
+    assert_eq!(pin.update_events_at(57)?, Some(Edge::Released));
+    assert!(pin.is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_handles_timer_wraparound() -> Result<(), Infallible> {
+    let mut pin: InterruptDebouncer<_, u16> = InterruptDebouncer::new(ActiveLow, 20);
+    assert_eq!(pin.on_edge(u16::MAX - 9, false), DebounceState::Debouncing);
+    assert_eq!(pin.next_deadline(), Some(10));
+    assert_eq!(pin.update_at(9)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(10)?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_syncs_to_a_pin_held_at_startup() -> Result<(), Infallible> {
+    let mut pin = InterruptDebouncer::<_, u32>::new(ActiveLow, 20);
+    assert_eq!(pin.sync(false), DebounceState::Active);
+    assert!(pin.is_settled());
+    assert!(pin.is_low()?);
+
+    assert_eq!(pin.on_edge(100, true), DebounceState::Reset);
+    assert_eq!(pin.sync(false), DebounceState::Active);
+    assert_eq!(pin.sync(true), DebounceState::NotActive);
+    Ok(())
+}
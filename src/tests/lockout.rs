@@ -0,0 +1,49 @@
+//! Tests for `LockoutDebouncer<T, A, Th>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, LockoutDebouncer};
+
+/// Creates a `LockoutDebouncer<MockInputPin, ActiveHigh, ConstThreshold<3>>`.
+fn create_pin() -> LockoutDebouncer<MockInputPin, ActiveHigh, ConstThreshold<3>> {
+    LockoutDebouncer::new_with_threshold(MockInputPin::default(), ActiveHigh)
+}
+
+#[test]
+fn it_reports_the_first_edge_immediately() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = true;
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_high()?);
+    assert!(pin.is_locked_out());
+    Ok(())
+}
+
+#[test]
+fn it_ignores_bounces_during_the_lockout() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.pin.state = true;
+    assert_eq!(pin.update()?, DebounceState::Active);
+    for &sample in &[false, true, false] {
+        pin.pin.state = sample;
+        assert_eq!(pin.update()?, DebounceState::Active);
+    }
+    assert!(!pin.is_locked_out());
+    assert_eq!(pin.update()?, DebounceState::Reset);
+    for &sample in &[true, false, true] {
+        pin.pin.state = sample;
+        assert_eq!(pin.update()?, DebounceState::NotActive);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_debounces_active_low_pins() -> Result<(), MockInputPinError> {
+    let mut pin = LockoutDebouncer::new(MockInputPin { state: true }, ActiveLow);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = false;
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Tests for `LongPress<D>`.
+
+use super::*;
+use crate::{ActiveHigh, LongPress, PressEvent};
+
+/// Creates a `LongPress` with a 5 tick threshold around a pin with a 2 tick threshold.
+fn create_pin() -> LongPress<DebouncedInputPin<MockInputPin, ActiveHigh, ConstThreshold<2>>> {
+    let pin = DebouncedInputPin::new_with_threshold(MockInputPin::default(), ActiveHigh);
+    LongPress::new(pin, 5)
+}
+
+/// Presses the pin and updates until it is debounced.
+fn press(
+    pin: &mut LongPress<DebouncedInputPin<MockInputPin, ActiveHigh, ConstThreshold<2>>>,
+) -> Result<(), MockInputPinError> {
+    pin.pin.pin.state = true;
+    for _ in 0..3 {
+        assert_eq!(pin.update()?, None);
+    }
+    assert!(pin.pin.is_active());
+    Ok(())
+}
+
+#[test]
+fn it_reports_a_short_press() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    press(&mut pin)?;
+    for _ in 0..4 {
+        assert_eq!(pin.update()?, None);
+    }
+    pin.pin.pin.state = false;
+    assert_eq!(pin.update()?, Some(PressEvent::ShortPress));
+    assert_eq!(pin.update()?, None);
+    Ok(())
+}
+
+#[test]
+fn it_reports_a_long_press_while_held() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    press(&mut pin)?;
+    for _ in 0..4 {
+        assert_eq!(pin.update()?, None);
+    }
+    assert_eq!(pin.update()?, Some(PressEvent::LongPressStart));
+    assert!(pin.is_long_press());
+    for _ in 0..10 {
+        assert_eq!(pin.update()?, None);
+    }
+    pin.pin.pin.state = false;
+    assert_eq!(pin.update()?, Some(PressEvent::LongPressRelease));
+    Ok(())
+}
+
+#[test]
+fn it_starts_over_on_the_next_press() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    press(&mut pin)?;
+    for _ in 0..5 {
+        pin.update()?;
+    }
+    pin.pin.pin.state = false;
+    assert_eq!(pin.update()?, Some(PressEvent::LongPressRelease));
+    press(&mut pin)?;
+    assert!(!pin.is_long_press());
+    pin.pin.pin.state = false;
+    assert_eq!(pin.update()?, Some(PressEvent::ShortPress));
+    Ok(())
+}
+
+#[test]
+fn it_is_not_settled_until_a_held_press_is_long() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert!(pin.is_settled());
+    press(&mut pin)?;
+    for _ in 0..4 {
+        assert!(!pin.is_settled());
+        pin.update()?;
+    }
+    assert_eq!(pin.update()?, Some(PressEvent::LongPressStart));
+    assert!(pin.is_settled());
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Tests for `KeyMatrix<R, C, ROWS, COLS>`.
+
+use super::*;
+use crate::{GhostPolicy, KeyEvent, KeyMatrix, MatrixError};
+use core::cell::Cell;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+
+/// A mock implementation of a row `OutputPin` which selects its row when low.
+struct MockRowPin<'a> {
+    index: usize,
+    selected: &'a Cell<Option<usize>>,
+}
+
+impl ErrorType for MockRowPin<'_> {
+    type Error = MockInputPinError;
+}
+
+impl OutputPin for MockRowPin<'_> {
+    fn set_low(&mut self) -> Result<(), MockInputPinError> {
+        self.selected.set(Some(self.index));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), MockInputPinError> {
+        if self.selected.get() == Some(self.index) {
+            self.selected.set(None);
+        }
+        Ok(())
+    }
+}
+
+/// A mock implementation of a column `InputPin` which is low when a key on the
+/// selected row is down.
+struct MockColPin<'a> {
+    index: usize,
+    selected: &'a Cell<Option<usize>>,
+    down: &'a Cell<[[bool; 2]; 2]>,
+    fails: bool,
+}
+
+impl ErrorType for MockColPin<'_> {
+    type Error = MockInputPinError;
+}
+
+impl InputPin for MockColPin<'_> {
+    fn is_high(&mut self) -> Result<bool, MockInputPinError> {
+        Ok(!self.is_low()?)
+    }
+
+    fn is_low(&mut self) -> Result<bool, MockInputPinError> {
+        if self.fails {
+            return Err(MockInputPinError);
+        }
+        Ok(self
+            .selected
+            .get()
+            .is_some_and(|row| self.down.get()[row][self.index]))
+    }
+}
+
+/// A mock implementation of `DelayNs` which records the requested delays.
+#[derive(Default)]
+struct MockDelay {
+    total_ns: u32,
+}
+
+impl DelayNs for MockDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += ns;
+    }
+}
+
+type Matrix<'a> = KeyMatrix<MockRowPin<'a>, MockColPin<'a>, 2, 2>;
+
+/// Creates a 2 by 2 `KeyMatrix` with a threshold of 1 scan.
+fn create_matrix<'a>(
+    selected: &'a Cell<Option<usize>>,
+    down: &'a Cell<[[bool; 2]; 2]>,
+) -> Matrix<'a> {
+    let rows = [0, 1].map(|index| MockRowPin { index, selected });
+    let cols = [0, 1].map(|index| MockColPin {
+        index,
+        selected,
+        down,
+        fails: false,
+    });
+    let mut matrix = KeyMatrix::new(rows, cols);
+    matrix.set_threshold(1);
+    matrix
+}
+
+/// Scans the matrix once and returns the events.
+fn scan(
+    matrix: &mut Matrix<'_>,
+) -> Result<[Option<KeyEvent>; 4], MatrixError<MockInputPinError, MockInputPinError>> {
+    let mut events = [None; 4];
+    let mut count = 0;
+    matrix.scan(&mut MockDelay::default(), |event| {
+        events[count] = Some(event);
+        count += 1;
+    })?;
+    Ok(events)
+}
+
+#[test]
+fn it_reports_debounced_key_down_and_up(
+) -> Result<(), MatrixError<MockInputPinError, MockInputPinError>> {
+    let selected = Cell::new(None);
+    let down = Cell::new([[false, false], [false, true]]);
+    let mut matrix = create_matrix(&selected, &down);
+
+    assert_eq!(scan(&mut matrix)?, [None; 4]);
+    assert_eq!(
+        scan(&mut matrix)?[0],
+        Some(KeyEvent {
+            row: 1,
+            col: 1,
+            edge: Edge::Pressed,
+            ghost: false,
+        })
+    );
+    assert!(matrix.is_down(1, 1));
+    assert!(!matrix.is_down(0, 1));
+    assert_eq!(matrix.key_state(1, 1), DebounceState::Active);
+
+    down.set([[false, false], [false, false]]);
+    assert_eq!(
+        scan(&mut matrix)?[0],
+        Some(KeyEvent {
+            row: 1,
+            col: 1,
+            edge: Edge::Released,
+            ghost: false,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn it_ignores_bounces_shorter_than_the_threshold(
+) -> Result<(), MatrixError<MockInputPinError, MockInputPinError>> {
+    let selected = Cell::new(None);
+    let down = Cell::new([[true, false], [false, false]]);
+    let mut matrix = create_matrix(&selected, &down);
+    matrix.set_threshold(3);
+
+    for _ in 0..3 {
+        assert_eq!(scan(&mut matrix)?, [None; 4]);
+    }
+    down.set([[false, false], [false, false]]);
+    assert_eq!(scan(&mut matrix)?, [None; 4]);
+    assert!(!matrix.is_down(0, 0));
+    Ok(())
+}
+
+#[test]
+fn it_waits_for_the_rows_to_settle() -> Result<(), MatrixError<MockInputPinError, MockInputPinError>>
+{
+    let selected = Cell::new(None);
+    let down = Cell::new([[false; 2]; 2]);
+    let mut matrix = create_matrix(&selected, &down);
+    matrix.set_settle_us(5);
+
+    let mut delay = MockDelay::default();
+    matrix.scan(&mut delay, |_| {})?;
+    assert_eq!(delay.total_ns, 10_000);
+    assert_eq!(selected.get(), None);
+    Ok(())
+}
+
+#[test]
+fn it_flags_ambiguous_keys() -> Result<(), MatrixError<MockInputPinError, MockInputPinError>> {
+    let selected = Cell::new(None);
+    let down = Cell::new([[true, true], [true, true]]);
+    let mut matrix = create_matrix(&selected, &down);
+
+    scan(&mut matrix)?;
+    let events = scan(&mut matrix)?;
+    assert_eq!(events.iter().flatten().count(), 4);
+    assert!(events.iter().flatten().all(|event| event.ghost));
+    assert!(matrix.is_ghost(0, 0));
+    assert!(matrix.is_ghost(1, 1));
+    assert!(matrix.is_down(1, 0));
+
+    down.set([[true, true], [false, false]]);
+    scan(&mut matrix)?;
+    assert!(!matrix.is_ghost(0, 0));
+    Ok(())
+}
+
+#[test]
+fn it_suppresses_ambiguous_keys() -> Result<(), MatrixError<MockInputPinError, MockInputPinError>> {
+    let selected = Cell::new(None);
+    let down = Cell::new([[true, false], [false, false]]);
+    let mut matrix = create_matrix(&selected, &down);
+    matrix.set_ghost_policy(GhostPolicy::Suppress);
+
+    scan(&mut matrix)?;
+    assert!(scan(&mut matrix)?[0].is_some());
+
+    down.set([[true, true], [true, true]]);
+    scan(&mut matrix)?;
+    assert_eq!(scan(&mut matrix)?, [None; 4]);
+    assert!(matrix.is_ghost(0, 1));
+    assert!(matrix.is_down(0, 0));
+    assert!(!matrix.is_down(0, 1));
+
+    down.set([[true, true], [false, false]]);
+    assert_eq!(
+        scan(&mut matrix)?,
+        [
+            Some(KeyEvent {
+                row: 0,
+                col: 1,
+                edge: Edge::Pressed,
+                ghost: false,
+            }),
+            None,
+            None,
+            None,
+        ]
+    );
+    assert!(!matrix.is_down(1, 0));
+    Ok(())
+}
+
+#[test]
+fn it_releases_the_row_when_a_column_fails() {
+    let selected = Cell::new(None);
+    let down = Cell::new([[false; 2]; 2]);
+    let mut matrix = create_matrix(&selected, &down);
+    matrix.cols[1].fails = true;
+
+    assert!(matches!(
+        scan(&mut matrix),
+        Err(MatrixError::Column(MockInputPinError))
+    ));
+    assert_eq!(selected.get(), None);
+}
@@ -0,0 +1,48 @@
+//! Tests for `Polarity`.
+
+use super::*;
+use crate::{LockoutDebouncer, Polarity};
+
+/// Creates a `DebouncedInputPin` with a threshold of 1 and a runtime polarity.
+fn create_pin(
+    polarity: Polarity,
+    state: bool,
+) -> DebouncedInputPin<MockInputPin, Polarity, ConstThreshold<1>> {
+    DebouncedInputPin::new_with_threshold(MockInputPin { state }, polarity)
+}
+
+#[test]
+fn it_handles_both_polarities_with_one_type() -> Result<(), MockInputPinError> {
+    let mut pins = [
+        create_pin(Polarity::ActiveHigh, true),
+        create_pin(Polarity::ActiveLow, false),
+    ];
+    for pin in pins.iter_mut() {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+        assert_eq!(pin.update()?, DebounceState::Active);
+    }
+    assert!(pins[0].is_high()?);
+    assert!(pins[1].is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_ignores_the_inactive_level() -> Result<(), MockInputPinError> {
+    let mut high = create_pin(Polarity::ActiveHigh, false);
+    let mut low = create_pin(Polarity::ActiveLow, true);
+    for _ in 0..3 {
+        assert_eq!(high.update()?, DebounceState::NotActive);
+        assert_eq!(low.update()?, DebounceState::NotActive);
+    }
+    assert!(high.is_low()?);
+    assert!(low.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_works_with_other_debouncers() -> Result<(), MockInputPinError> {
+    let mut pin = LockoutDebouncer::new(MockInputPin { state: false }, Polarity::ActiveLow);
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Tests for `PortDebouncer<W>`.
+
+use crate::{PortDebouncer, PortState};
+
+#[test]
+fn it_debounces_after_four_samples() {
+    let mut port = PortDebouncer::<u8>::new(0);
+    for _ in 0..3 {
+        assert_eq!(port.update(0b0000_0101).stable, 0);
+    }
+    assert_eq!(
+        port.update(0b0000_0101),
+        PortState {
+            stable: 0b0000_0101,
+            pressed: 0b0000_0101,
+            released: 0,
+        }
+    );
+    assert_eq!(port.update(0b0000_0101).pressed, 0);
+}
+
+#[test]
+fn it_restarts_a_bit_on_a_bounce() {
+    let mut port = PortDebouncer::<u16>::new(0);
+    port.update(0x0101);
+    port.update(0x0101);
+    port.update(0x0100);
+    for _ in 0..3 {
+        assert_eq!(port.update(0x0101).stable, 0x0100);
+    }
+    assert_eq!(port.update(0x0101).stable, 0x0101);
+}
+
+#[test]
+fn it_reports_released_bits() {
+    let mut port = PortDebouncer::<u32>::new(0);
+    for _ in 0..4 {
+        port.update(0b11);
+    }
+    for _ in 0..3 {
+        assert_eq!(port.update(0b01).released, 0);
+    }
+    assert_eq!(
+        port.update(0b01),
+        PortState {
+            stable: 0b01,
+            pressed: 0,
+            released: 0b10,
+        }
+    );
+}
+
+#[test]
+fn it_inverts_active_low_bits() {
+    let mut port = PortDebouncer::<u8>::new(0b1111_0000);
+    for _ in 0..4 {
+        port.update(0b1111_0000);
+    }
+    assert_eq!(port.stable(), 0);
+    for _ in 0..4 {
+        port.update(0b0111_0001);
+    }
+    assert_eq!(port.stable(), 0b1000_0001);
+}
+
+#[test]
+fn it_is_settled_while_no_bit_is_debouncing() {
+    let mut port = PortDebouncer::<u8>::new(0b1000_0000);
+    assert!(port.is_settled());
+    port.update(0b1000_0000);
+    assert!(port.is_settled());
+    port.update(0b1000_0001);
+    assert!(!port.is_settled());
+    for _ in 0..3 {
+        port.update(0b1000_0001);
+    }
+    assert!(port.is_settled());
+}
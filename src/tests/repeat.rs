@@ -0,0 +1,78 @@
+//! Tests for `AutoRepeat<D>`.
+
+use super::*;
+use crate::{ActiveHigh, AutoRepeat, RepeatEvent};
+
+type Repeater = AutoRepeat<DebouncedInputPin<MockInputPin, ActiveHigh, ConstThreshold<1>>>;
+
+/// Creates an `AutoRepeat` with a 4 tick delay and a 2 tick interval.
+fn create_pin() -> Repeater {
+    let pin = DebouncedInputPin::new_with_threshold(MockInputPin::default(), ActiveHigh);
+    AutoRepeat::new(pin, 4, 2)
+}
+
+/// Presses the pin and updates until the press is reported.
+fn press(pin: &mut Repeater) -> Result<(), MockInputPinError> {
+    pin.pin.pin.state = true;
+    assert_eq!(pin.update()?, None);
+    assert_eq!(pin.update()?, Some(RepeatEvent::Pressed));
+    Ok(())
+}
+
+/// Returns the number of ticks until the next event.
+fn ticks_until_event(pin: &mut Repeater) -> Result<u16, MockInputPinError> {
+    let mut ticks = 1;
+    while pin.update()?.is_none() {
+        ticks += 1;
+    }
+    Ok(ticks)
+}
+
+#[test]
+fn it_repeats_after_the_delay_at_the_interval() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    press(&mut pin)?;
+    assert_eq!(ticks_until_event(&mut pin)?, 4);
+    assert_eq!(pin.state(), Some(RepeatEvent::Repeat));
+    assert_eq!(ticks_until_event(&mut pin)?, 2);
+    assert_eq!(ticks_until_event(&mut pin)?, 2);
+    Ok(())
+}
+
+#[test]
+fn it_stops_repeating_on_release() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    press(&mut pin)?;
+    pin.pin.pin.state = false;
+    for _ in 0..10 {
+        assert_eq!(pin.update()?, None);
+    }
+    press(&mut pin)?;
+    assert_eq!(ticks_until_event(&mut pin)?, 4);
+    Ok(())
+}
+
+#[test]
+fn it_accelerates_down_to_the_minimum_interval() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.set_interval(6);
+    pin.set_acceleration(2, 3);
+    press(&mut pin)?;
+    assert_eq!(ticks_until_event(&mut pin)?, 4);
+    assert_eq!(ticks_until_event(&mut pin)?, 6);
+    assert_eq!(ticks_until_event(&mut pin)?, 4);
+    assert_eq!(ticks_until_event(&mut pin)?, 3);
+    assert_eq!(ticks_until_event(&mut pin)?, 3);
+    Ok(())
+}
+
+#[test]
+fn it_never_grows_the_interval_above_the_minimum() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.set_acceleration(1, 5);
+    press(&mut pin)?;
+    assert_eq!(ticks_until_event(&mut pin)?, 4);
+    assert_eq!(ticks_until_event(&mut pin)?, 2);
+    assert_eq!(ticks_until_event(&mut pin)?, 2);
+    Ok(())
+}
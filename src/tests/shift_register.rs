@@ -0,0 +1,56 @@
+//! Tests for `ShiftRegisterDebouncer<T, A, H>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, ShiftRegisterDebouncer};
+
+#[test]
+fn it_goes_active_when_the_register_is_full() -> Result<(), MockInputPinError> {
+    let mut pin = ShiftRegisterDebouncer::new(MockInputPin::default(), ActiveHigh);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    pin.pin.state = true;
+    for _ in 0..7 {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_restarts_on_a_bounce() -> Result<(), MockInputPinError> {
+    let mut pin = ShiftRegisterDebouncer::new(MockInputPin::default(), ActiveHigh);
+    pin.pin.state = true;
+    for _ in 0..6 {
+        pin.update()?;
+    }
+    pin.pin.state = false;
+    assert_eq!(pin.update()?, DebounceState::Debouncing);
+    pin.pin.state = true;
+    for _ in 0..7 {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_releases_when_the_register_is_empty() -> Result<(), MockInputPinError> {
+    let mut pin = ShiftRegisterDebouncer::<_, _, u16>::new_with_history(
+        MockInputPin { state: false },
+        ActiveLow,
+    );
+    for _ in 0..15 {
+        assert_eq!(pin.update()?, DebounceState::Debouncing);
+    }
+    assert_eq!(pin.update()?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    pin.pin.state = true;
+    for _ in 0..15 {
+        assert_eq!(pin.update()?, DebounceState::Releasing);
+        assert!(pin.is_low()?);
+    }
+    assert_eq!(pin.update()?, DebounceState::Reset);
+    assert!(pin.is_high()?);
+    assert_eq!(pin.update()?, DebounceState::NotActive);
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Tests for `DebouncedInputPin::split()`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, PinUpdater, SharedPinState};
+use core::cell::Cell;
+use core::convert::Infallible;
+
+/// A mock implementation of `InputPin` whose level is shared with the test, so
+/// it can be changed after the pin is split.
+struct LevelPin<'a> {
+    high: &'a Cell<bool>,
+}
+
+impl ErrorType for LevelPin<'_> {
+    type Error = Infallible;
+}
+
+impl InputPin for LevelPin<'_> {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.high.get())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.high.get())
+    }
+}
+
+/// Creates an active-high `DebouncedInputPin` with a threshold of 1 on `high`.
+fn create_pin(high: &Cell<bool>) -> DebouncedInputPin<LevelPin<'_>, ActiveHigh, ConstThreshold<1>> {
+    DebouncedInputPin::new_with_threshold(LevelPin { high }, ActiveHigh)
+}
+
+/// Presses and releases the pin once.
+fn click(
+    updater: &mut PinUpdater<'_, LevelPin<'_>, ActiveHigh, ConstThreshold<1>>,
+    high: &Cell<bool>,
+) -> Result<(), Infallible> {
+    high.set(true);
+    updater.update()?;
+    updater.update()?;
+    high.set(false);
+    updater.update()?;
+    Ok(())
+}
+
+#[test]
+fn it_publishes_the_state_to_the_reader() -> Result<(), Infallible> {
+    let high = Cell::new(false);
+    let mut shared = SharedPinState::new();
+    let (mut updater, reader) = create_pin(&high).split(&mut shared);
+    assert_eq!(reader.state(), DebounceState::NotActive);
+    high.set(true);
+    assert_eq!(updater.update()?, DebounceState::Debouncing);
+    assert_eq!(reader.state(), DebounceState::Debouncing);
+    assert_eq!(updater.update()?, DebounceState::Active);
+    assert_eq!(reader.state(), DebounceState::Active);
+    assert!(reader.is_active());
+    Ok(())
+}
+
+#[test]
+fn it_latches_edges_between_reads() -> Result<(), Infallible> {
+    let high = Cell::new(false);
+    let mut shared = SharedPinState::new();
+    let (mut updater, mut reader) = create_pin(&high).split(&mut shared);
+    for _ in 0..2 {
+        click(&mut updater, &high)?;
+    }
+    assert!(!reader.is_active());
+    assert_eq!(reader.take_presses(), 2);
+    assert_eq!(reader.take_releases(), 2);
+    assert_eq!(reader.take_presses(), 0);
+    Ok(())
+}
+
+#[test]
+fn it_takes_edges_separately_for_every_reader() -> Result<(), Infallible> {
+    let high = Cell::new(false);
+    let mut shared = SharedPinState::new();
+    let (mut updater, mut reader) = create_pin(&high).split(&mut shared);
+    let mut other = reader.clone();
+    click(&mut updater, &high)?;
+    assert_eq!(reader.take_presses(), 1);
+    assert_eq!(other.take_presses(), 1);
+    Ok(())
+}
+
+#[test]
+fn it_publishes_and_latches_a_reset() -> Result<(), Infallible> {
+    let high = Cell::new(true);
+    let mut shared = SharedPinState::new();
+    let (mut updater, mut reader) = create_pin(&high).split(&mut shared);
+    assert_eq!(updater.sync()?, DebounceState::Active);
+    assert_eq!(reader.take_presses(), 1);
+
+    updater.reset();
+    assert!(!reader.is_active());
+    updater.update()?;
+    assert_eq!(reader.take_releases(), 1);
+    assert_eq!(updater.pin().state(), DebounceState::Debouncing);
+    Ok(())
+}
+
+#[test]
+fn it_reads_as_a_debounced_pin() -> Result<(), Infallible> {
+    let mut shared = SharedPinState::new();
+    let pin = DebouncedInputPin::<_, _>::new_synced(MockInputPin { state: false }, ActiveLow)
+        .unwrap_or_else(|_| unreachable!());
+    let (_updater, mut reader) = pin.split(&mut shared);
+    assert!(reader.is_low()?);
+    assert_eq!(reader.state(), DebounceState::Active);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Tests for `TimedDebouncer<T, A, I>`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, TimedDebouncer};
+
+/// Creates a `TimedDebouncer<MockInputPin, ActiveHigh, u32>` with a 20 tick window.
+fn create_pin() -> TimedDebouncer<MockInputPin, ActiveHigh, u32> {
+    TimedDebouncer::new(MockInputPin::default(), ActiveHigh, 20)
+}
+
+#[test]
+fn it_goes_active_after_the_window_regardless_of_call_rate() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    assert_eq!(pin.update_at(0)?, DebounceState::NotActive);
+    pin.pin.state = true;
+    assert_eq!(pin.update_at(1)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(6)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(20)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(21)?, DebounceState::Active);
+    assert!(pin.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_restarts_the_window_on_a_bounce() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.pin.state = true;
+    assert_eq!(pin.update_at(0)?, DebounceState::Debouncing);
+    pin.pin.state = false;
+    assert_eq!(pin.update_at(15)?, DebounceState::NotActive);
+    pin.pin.state = true;
+    assert_eq!(pin.update_at(16)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(30)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(36)?, DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_debounces_the_release_over_the_release_window() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin();
+    pin.set_release_window(5);
+    pin.pin.state = true;
+    pin.update_at(0)?;
+    assert_eq!(pin.update_at(20)?, DebounceState::Active);
+    pin.pin.state = false;
+    assert_eq!(pin.update_at(22)?, DebounceState::Releasing);
+    assert!(pin.is_high()?);
+    assert_eq!(pin.update_at(27)?, DebounceState::Reset);
+    assert!(pin.is_low()?);
+    assert_eq!(pin.update_at(28)?, DebounceState::NotActive);
+    Ok(())
+}
+
+#[test]
+fn it_handles_timer_wraparound() -> Result<(), MockInputPinError> {
+    let mut pin: TimedDebouncer<_, _, u16> =
+        TimedDebouncer::new(MockInputPin { state: false }, ActiveLow, 20);
+    assert_eq!(pin.update_at(u16::MAX - 9)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(9)?, DebounceState::Debouncing);
+    assert_eq!(pin.update_at(10)?, DebounceState::Active);
+    assert!(pin.is_low()?);
+    Ok(())
+}
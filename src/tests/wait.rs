@@ -0,0 +1,194 @@
+//! Tests for `AsyncDebouncer`.
+
+use super::*;
+use crate::{ActiveHigh, ActiveLow, AlwaysPoll, AsyncDebouncer};
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+
+/// Runs a future which never has to wait to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is waiting"),
+    }
+}
+
+/// An input pin which reads a script of levels, one per read.
+struct ScriptPin {
+    /// The levels of the next reads, after which the last level repeats.
+    script: &'static [bool],
+    /// The number of reads so far.
+    reads: usize,
+    /// The number of calls to `Wait` methods.
+    waits: usize,
+}
+
+impl ScriptPin {
+    fn new(script: &'static [bool]) -> Self {
+        Self {
+            script,
+            reads: 0,
+            waits: 0,
+        }
+    }
+
+    fn level(&self) -> bool {
+        let index = self.reads.min(self.script.len() - 1);
+        self.script[index]
+    }
+
+    /// Skips the reads until the pin is at `level`, failing if the script ends
+    /// at the other level.
+    fn skip_until(&mut self, level: bool) -> Result<(), MockInputPinError> {
+        while self.level() != level {
+            if self.reads >= self.script.len() {
+                return Err(MockInputPinError);
+            }
+            self.reads += 1;
+        }
+        Ok(())
+    }
+}
+
+impl ErrorType for ScriptPin {
+    type Error = MockInputPinError;
+}
+
+impl InputPin for ScriptPin {
+    fn is_high(&mut self) -> Result<bool, MockInputPinError> {
+        let level = self.level();
+        self.reads += 1;
+        Ok(level)
+    }
+
+    fn is_low(&mut self) -> Result<bool, MockInputPinError> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+impl Wait for ScriptPin {
+    /// Skips the reads until the pin is high.
+    async fn wait_for_high(&mut self) -> Result<(), MockInputPinError> {
+        self.waits += 1;
+        self.skip_until(true)
+    }
+
+    /// Skips the reads until the pin is low.
+    async fn wait_for_low(&mut self) -> Result<(), MockInputPinError> {
+        self.waits += 1;
+        self.skip_until(false)
+    }
+
+    /// Skips the reads until the pin goes from low to high.
+    async fn wait_for_rising_edge(&mut self) -> Result<(), MockInputPinError> {
+        self.waits += 1;
+        self.skip_until(false)?;
+        self.skip_until(true)
+    }
+
+    /// Skips the reads until the pin goes from high to low.
+    async fn wait_for_falling_edge(&mut self) -> Result<(), MockInputPinError> {
+        self.waits += 1;
+        self.skip_until(true)?;
+        self.skip_until(false)
+    }
+
+    /// Skips the reads until the pin changes its level.
+    async fn wait_for_any_edge(&mut self) -> Result<(), MockInputPinError> {
+        self.waits += 1;
+        let level = self.level();
+        self.skip_until(!level)
+    }
+}
+
+/// An async delay which returns immediately and counts the time waited.
+#[derive(Default)]
+struct MockAsyncDelay {
+    total_ns: u64,
+}
+
+impl DelayNs for MockAsyncDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += u64::from(ns);
+    }
+}
+
+const PRESS: &[bool] = &[false, false, true, false, true, true, true, true, false];
+const LOW_PRESS: &[bool] = &[true, false, true, false, false, false, false, true];
+
+fn create_pin(
+    script: &'static [bool],
+) -> AsyncDebouncer<ScriptPin, ActiveHigh, MockAsyncDelay, AlwaysPoll, ConstThreshold<3>> {
+    let pin = DebouncedInputPin::<_, _, ConstThreshold<3>>::new_with_threshold(
+        ScriptPin::new(script),
+        ActiveHigh,
+    );
+    AsyncDebouncer::new(pin, MockAsyncDelay::default(), 1_000)
+}
+
+#[test]
+fn it_waits_for_a_debounced_press() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin(PRESS);
+    block_on(pin.wait_for_press())?;
+    assert_eq!(pin.pin.pin.reads, 7);
+    assert_eq!(pin.pin.state(), DebounceState::Active);
+    Ok(())
+}
+
+#[test]
+fn it_waits_one_interval_per_sample() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin(PRESS);
+    pin.set_interval_us(2_000);
+    block_on(pin.wait_for_press())?;
+    assert_eq!(pin.delay.total_ns, 7 * 2_000_000);
+    Ok(())
+}
+
+#[test]
+fn it_reports_edges() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin(PRESS);
+    assert_eq!(block_on(pin.wait_for_edge())?, Edge::Pressed);
+    assert_eq!(block_on(pin.wait_for_edge())?, Edge::Released);
+    Ok(())
+}
+
+#[test]
+fn it_waits_for_debounced_levels() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin(PRESS);
+    block_on(pin.wait_for_high())?;
+    assert!(pin.pin.is_high()?);
+    block_on(pin.wait_for_high())?;
+    assert_eq!(pin.pin.pin.reads, 7);
+    block_on(pin.wait_for_low())?;
+    assert!(pin.pin.is_low()?);
+    Ok(())
+}
+
+#[test]
+fn it_waits_for_debounced_edges() -> Result<(), MockInputPinError> {
+    let pin = DebouncedInputPin::<_, _, ConstThreshold<3>>::new_with_threshold(
+        ScriptPin::new(LOW_PRESS),
+        ActiveLow,
+    );
+    let mut pin = AsyncDebouncer::new(pin, MockAsyncDelay::default(), 1_000);
+    block_on(pin.wait_for_falling_edge())?;
+    assert!(pin.pin.is_low()?);
+    block_on(pin.wait_for_rising_edge())?;
+    assert!(pin.pin.is_high()?);
+    Ok(())
+}
+
+#[test]
+fn it_sleeps_on_the_pin_while_steady() -> Result<(), MockInputPinError> {
+    let mut pin = create_pin(PRESS).with_pin_wait();
+    block_on(pin.wait_for_press())?;
+    assert_eq!(pin.pin.pin.waits, 2);
+    // The first reads are skipped while sleeping instead of being sampled.
+    assert_eq!(pin.delay.total_ns, 5 * 1_000_000);
+    Ok(())
+}
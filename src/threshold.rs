@@ -0,0 +1,35 @@
+//! Compile-time and runtime debounce thresholds.
+
+/// The number of ticks a debouncer counts to, such as the active ticks a
+/// `DebouncedInputPin` requires before it is debounced or the lockout window of
+/// a `LockoutDebouncer`.
+pub trait Threshold {
+    /// Returns the number of ticks.
+    fn ticks(&self) -> u16;
+}
+
+/// A threshold of `N` ticks fixed at compile time, which takes no space.
+///
+/// The default threshold of `DebouncedInputPin`, `IntegratingDebouncer` and
+/// `LockoutDebouncer`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct ConstThreshold<const N: u16>;
+
+/// A threshold which can be changed at runtime with
+/// `DebouncedInputPin::set_threshold()`.
+///
+/// Used by pins created with `DebouncedInputPin::builder()`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RuntimeThreshold(pub(crate) u16);
+
+impl<const N: u16> Threshold for ConstThreshold<N> {
+    fn ticks(&self) -> u16 {
+        N
+    }
+}
+
+impl Threshold for RuntimeThreshold {
+    fn ticks(&self) -> u16 {
+        self.0
+    }
+}
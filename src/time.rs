@@ -0,0 +1,37 @@
+//! Timestamps for time-based debouncing.
+
+/// A monotonic timestamp, such as a tick count from a hardware timer.
+///
+/// Implemented for `u16`, `u32` and `u64` tick counts, which are allowed to
+/// wrap around. Durations are measured in the same ticks, so a 1 kHz timer
+/// gives durations in milliseconds.
+pub trait Instant: Copy {
+    /// The time between two instants.
+    type Duration: Copy + Default + PartialOrd;
+
+    /// Returns the time elapsed from `earlier` to `self`.
+    fn duration_since(self, earlier: Self) -> Self::Duration;
+
+    /// Returns the instant `duration` after `self`.
+    fn add_duration(self, duration: Self::Duration) -> Self;
+}
+
+macro_rules! impl_instant {
+    ($($t:ty),*) => {
+        $(
+            impl Instant for $t {
+                type Duration = $t;
+
+                fn duration_since(self, earlier: Self) -> Self::Duration {
+                    self.wrapping_sub(earlier)
+                }
+
+                fn add_duration(self, duration: Self::Duration) -> Self {
+                    self.wrapping_add(duration)
+                }
+            }
+        )*
+    };
+}
+
+impl_instant!(u16, u32, u64);
@@ -0,0 +1,133 @@
+//! A time-based debouncer.
+
+use crate::{Activeness, DebounceAt, DebounceState, Instant};
+use embedded_hal::digital::InputPin;
+
+/// An input pin debounced over a duration instead of a number of updates.
+///
+/// The pin is considered debounced once it has been active for `window`, as
+/// measured by the timestamps passed to `update_at()`. This keeps the window
+/// stable when `update_at()` is not called at a fixed rate.
+///
+/// By default a single inactive sample releases the pin. A release window set
+/// with `set_release_window()` debounces the release edge as well.
+pub struct TimedDebouncer<T: InputPin, A, I: Instant = u32> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: A,
+
+    /// The debounced pin state.
+    debounce_state: DebounceState,
+
+    /// When the pin started to differ from the debounced state.
+    changed_at: Option<I>,
+
+    /// How long the pin has to be active before it is debounced.
+    window: I::Duration,
+
+    /// How long the pin has to be inactive before it is released.
+    release_window: I::Duration,
+}
+
+impl<T: InputPin, A, I: Instant> TimedDebouncer<T, A, I> {
+    /// Initializes a new time-based debouncer.
+    ///
+    /// `window` is measured in the same units as the timestamps passed to
+    /// `update_at()`.
+    pub fn new(pin: T, activeness: A, window: I::Duration) -> Self {
+        Self {
+            pin,
+            activeness,
+            debounce_state: DebounceState::NotActive,
+            changed_at: None,
+            window,
+            release_window: I::Duration::default(),
+        }
+    }
+
+    /// Returns how long the pin has to be active before it is debounced.
+    pub fn window(&self) -> I::Duration {
+        self.window
+    }
+
+    /// Changes how long the pin has to be active before it is debounced.
+    pub fn set_window(&mut self, window: I::Duration) {
+        self.window = window;
+    }
+
+    /// Returns how long the pin has to be inactive before it is released.
+    pub fn release_window(&self) -> I::Duration {
+        self.release_window
+    }
+
+    /// Changes how long the pin has to be inactive before it is released.
+    pub fn set_release_window(&mut self, release_window: I::Duration) {
+        self.release_window = release_window;
+    }
+
+    /// Checks if the pin debounce state is active.
+    pub fn is_active(&self) -> bool {
+        self.debounce_state.is_active()
+    }
+
+    /// Drives the debounce logic with a timestamped sample of whether the pin is active.
+    fn debounce_at(&mut self, now: I, active: bool) -> DebounceState {
+        let was_active = self.debounce_state.is_active();
+        if active == was_active {
+            self.changed_at = None;
+            self.debounce_state = if active {
+                DebounceState::Active
+            } else {
+                DebounceState::NotActive
+            };
+            return self.debounce_state;
+        }
+
+        let changed_at = *self.changed_at.get_or_insert(now);
+        let elapsed = now.duration_since(changed_at);
+        self.debounce_state = if was_active {
+            if elapsed >= self.release_window {
+                self.changed_at = None;
+                DebounceState::Reset
+            } else {
+                DebounceState::Releasing
+            }
+        } else if elapsed >= self.window {
+            self.changed_at = None;
+            DebounceState::Active
+        } else {
+            DebounceState::Debouncing
+        };
+
+        self.debounce_state
+    }
+}
+
+impl<T: InputPin, A: Activeness, I: Instant> DebounceAt for TimedDebouncer<T, A, I> {
+    type Error = T::Error;
+    type State = DebounceState;
+    type Instant = I;
+
+    /// Returns the debounce state from the last update.
+    fn state(&self) -> Self::State {
+        self.debounce_state
+    }
+
+    /// Updates the debounce logic at the given time.
+    fn update_at(&mut self, now: I) -> Result<Self::State, Self::Error> {
+        let active = self.activeness.is_active(&mut self.pin)?;
+        Ok(self.debounce_at(now, active))
+    }
+
+    /// Checks if the pin is neither debouncing nor releasing.
+    fn is_settled(&self) -> bool {
+        self.changed_at.is_none()
+    }
+}
+
+impl_input_pin!(
+    impl<T: InputPin, A: Activeness, I: Instant> TimedDebouncer<T, A, I>,
+    T::Error
+);
@@ -0,0 +1,183 @@
+//! Async waiting for debounced pin changes.
+//!
+//! Available with the `async` feature.
+
+use crate::{
+    Activeness, ConstThreshold, Debounce, DebounceState, DebouncedInputPin, Edge, Threshold,
+};
+use core::future::Future;
+use embedded_hal::digital::{ErrorType, InputPin};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+
+/// How an `AsyncDebouncer` waits while the debounced pin is steady.
+pub trait Idle<P: ErrorType> {
+    /// Waits until the wrapped pin may have changed to the level `high`.
+    fn wait_for(&mut self, pin: &mut P, high: bool) -> impl Future<Output = Result<(), P::Error>>;
+}
+
+/// Keeps sampling the pin at the sampling interval while it is steady.
+pub struct AlwaysPoll;
+
+/// Sleeps on the wrapped pin's own `Wait` while the pin is steady.
+pub struct PinWait;
+
+impl<P: ErrorType> Idle<P> for AlwaysPoll {
+    async fn wait_for(&mut self, _pin: &mut P, _high: bool) -> Result<(), P::Error> {
+        Ok(())
+    }
+}
+
+impl<P: Wait> Idle<P> for PinWait {
+    async fn wait_for(&mut self, pin: &mut P, high: bool) -> Result<(), P::Error> {
+        if high {
+            pin.wait_for_high().await
+        } else {
+            pin.wait_for_low().await
+        }
+    }
+}
+
+/// A debounced pin which can be awaited instead of polled.
+///
+/// Drives `DebouncedInputPin::update()` every `interval_us` microseconds using
+/// an async delay, and implements `Wait` with debounced semantics: the levels
+/// and edges it waits for are those of the debounced pin.
+///
+/// By default the pin keeps being sampled while it is steady. With
+/// `with_pin_wait()`, the wrapped pin's own `Wait` is used instead to sleep
+/// until it changes.
+pub struct AsyncDebouncer<T: InputPin, A, D, S = AlwaysPoll, Th = ConstThreshold<10>> {
+    /// The debounced pin.
+    pub pin: DebouncedInputPin<T, A, Th>,
+
+    /// The delay used between samples.
+    pub delay: D,
+
+    /// The microseconds between samples.
+    interval_us: u32,
+
+    /// How to wait while the pin is steady.
+    idle: S,
+}
+
+impl<T: InputPin, A: Activeness, D: DelayNs, Th: Threshold>
+    AsyncDebouncer<T, A, D, AlwaysPoll, Th>
+{
+    /// Initializes a new async debouncer sampling `pin` every `interval_us` microseconds.
+    pub fn new(pin: DebouncedInputPin<T, A, Th>, delay: D, interval_us: u32) -> Self {
+        Self {
+            pin,
+            delay,
+            interval_us,
+            idle: AlwaysPoll,
+        }
+    }
+
+    /// Sleeps on the wrapped pin's own `Wait` while the pin is steady.
+    pub fn with_pin_wait(self) -> AsyncDebouncer<T, A, D, PinWait, Th>
+    where
+        T: Wait,
+    {
+        AsyncDebouncer {
+            pin: self.pin,
+            delay: self.delay,
+            interval_us: self.interval_us,
+            idle: PinWait,
+        }
+    }
+}
+
+impl<T: InputPin, A: Activeness, D: DelayNs, S: Idle<T>, Th: Threshold>
+    AsyncDebouncer<T, A, D, S, Th>
+{
+    /// Changes the microseconds between samples.
+    pub fn set_interval_us(&mut self, interval_us: u32) {
+        self.interval_us = interval_us;
+    }
+
+    /// Waits for the next sample and updates the debounce logic with it.
+    async fn sample(&mut self) -> Result<DebounceState, T::Error> {
+        let state = self.pin.state();
+        if let DebounceState::NotActive | DebounceState::Reset | DebounceState::Active = state {
+            // Wait for the level which would start debouncing a change.
+            let high = self.pin.is_active() != self.pin.activeness.is_high_when_active();
+            self.idle.wait_for(&mut self.pin.pin, high).await?;
+        }
+
+        self.delay.delay_us(self.interval_us).await;
+        self.pin.update()
+    }
+
+    /// Waits for the next debounced press or release.
+    pub async fn wait_for_edge(&mut self) -> Result<Edge, T::Error> {
+        loop {
+            let previous = self.pin.state();
+            let next = self.sample().await?;
+            if let Some(edge) = Edge::between(previous, next) {
+                return Ok(edge);
+            }
+        }
+    }
+
+    /// Waits for the next debounced press.
+    pub async fn wait_for_press(&mut self) -> Result<(), T::Error> {
+        while self.wait_for_edge().await? != Edge::Pressed {}
+        Ok(())
+    }
+
+    /// Waits for the next debounced release.
+    pub async fn wait_for_release(&mut self) -> Result<(), T::Error> {
+        while self.wait_for_edge().await? != Edge::Released {}
+        Ok(())
+    }
+
+    /// Waits until the debounced pin reads `high`.
+    async fn wait_for_level(&mut self, high: bool) -> Result<(), T::Error> {
+        while self.pin.is_high()? != high {
+            self.sample().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Waits until the debounced pin changes to `high`.
+    async fn wait_for_change_to(&mut self, high: bool) -> Result<(), T::Error> {
+        loop {
+            self.wait_for_edge().await?;
+            if self.pin.is_high()? == high {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<T: InputPin, A: Activeness, D: DelayNs, S: Idle<T>, Th: Threshold> ErrorType
+    for AsyncDebouncer<T, A, D, S, Th>
+{
+    type Error = T::Error;
+}
+
+impl<T: InputPin, A: Activeness, D: DelayNs, S: Idle<T>, Th: Threshold> Wait
+    for AsyncDebouncer<T, A, D, S, Th>
+{
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(true).await
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(false).await
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_change_to(true).await
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_change_to(false).await
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_edge().await.map(|_| ())
+    }
+}